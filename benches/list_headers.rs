@@ -0,0 +1,288 @@
+//! Benchmarks for the operations on a linked list set that look up the header
+//! of a linked list from its list index.
+//!
+//! The slot map that stores the list headers is compared against the 
+//! `FnvHashMap` keyed by list index that it replaced, which is reproduced 
+//! here with the same node storage as the set.
+//!
+//! Run with
+//! ```text
+//! cargo bench --bench list_headers
+//! ```
+extern crate list_set;
+
+
+use list_set::*;
+use std::collections::{
+    HashMap,
+};
+use std::hash::{
+    BuildHasherDefault,
+    Hasher,
+};
+use std::hint::{
+    black_box,
+};
+use std::time::{
+    Duration,
+    Instant,
+};
+
+
+const LIST_COUNT: usize = 10_000;
+const ITEMS_PER_LIST: usize = 16;
+const ROUNDS: usize = 20;
+
+
+/// The 64 bit Fowler-Noll-Vo hash function, as provided by the `fnv` crate.
+struct FnvHasher(u64);
+
+impl Default for FnvHasher {
+    fn default() -> FnvHasher {
+        FnvHasher(0xcbf29ce484222325)
+    }
+}
+
+impl Hasher for FnvHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        let FnvHasher(mut hash) = *self;
+        for byte in bytes.iter() {
+            hash ^= *byte as u64;
+            hash = hash.wrapping_mul(0x100000001b3);
+        }
+
+        *self = FnvHasher(hash);
+    }
+}
+
+type FnvHashMap<K, V> = HashMap<K, V, BuildHasherDefault<FnvHasher>>;
+
+
+/// The header of a linked list in the map-based set.
+struct MapList {
+    front: usize,
+    back: usize,
+    length: usize,
+}
+
+/// A linked list node in the map-based set.
+#[allow(dead_code)]
+struct MapNode {
+    item: usize,
+    list: ListIndex,
+    previous: usize,
+    next: usize,
+}
+
+/// A linked list set that looks up its list headers in an `FnvHashMap`.
+#[derive(Default)]
+struct MapListSet {
+    lists: FnvHashMap<ListIndex, MapList>,
+    nodes: Vec<MapNode>,
+}
+
+impl MapListSet {
+    fn new_list(&mut self, list_index: ListIndex) {
+        self.lists.insert(list_index, MapList {
+            front: usize::MAX,
+            back: usize::MAX,
+            length: 0,
+        });
+    }
+
+    fn push_back(&mut self, list_index: ListIndex, item: usize) {
+        let position = self.nodes.len();
+        let list = self.lists.get_mut(&list_index).unwrap();
+        self.nodes.push(MapNode {
+            item: item,
+            list: list_index,
+            previous: list.back,
+            next: usize::MAX,
+        });
+        if list.back == usize::MAX {
+            list.front = position;
+        } else {
+            self.nodes[list.back].next = position;
+        }
+        list.back = position;
+        list.length += 1;
+    }
+
+    fn pop_front(&mut self, list_index: ListIndex) -> Option<usize> {
+        let list = self.lists.get_mut(&list_index)?;
+        if list.length == 0 {
+            return None;
+        }
+
+        let position = list.front;
+        let next = self.nodes[position].next;
+        list.front = next;
+        list.length -= 1;
+        if next == usize::MAX {
+            list.back = usize::MAX;
+        } else {
+            self.nodes[next].previous = usize::MAX;
+        }
+
+        let node = self.nodes.swap_remove(position);
+        if position < self.nodes.len() {
+            // Point the neighbours and the list header of the node that moved
+            // into the freed position at its new position.
+            let moved = &self.nodes[position];
+            let (moved_list, previous, next) = (moved.list, moved.previous, moved.next);
+            let list = self.lists.get_mut(&moved_list).unwrap();
+            if previous == usize::MAX {
+                list.front = position;
+            } else {
+                self.nodes[previous].next = position;
+            }
+            if next == usize::MAX {
+                list.back = position;
+            } else {
+                self.nodes[next].previous = position;
+            }
+        }
+
+        Some(node.item)
+    }
+
+    fn len(&self, list_index: ListIndex) -> usize {
+        self.lists.get(&list_index).map_or(0, |list| list.length)
+    }
+
+    fn front(&self, list_index: ListIndex) -> Option<&usize> {
+        let list = self.lists.get(&list_index)?;
+        if list.length == 0 {
+            None
+        } else {
+            Some(&self.nodes[list.front].item)
+        }
+    }
+
+    fn contains_list(&self, list_index: ListIndex) -> bool {
+        self.lists.contains_key(&list_index)
+    }
+
+    fn sum(&self, list_index: ListIndex) -> usize {
+        let mut sum = 0;
+        let mut current = self.lists[&list_index].front;
+        while current != usize::MAX {
+            sum += self.nodes[current].item;
+            current = self.nodes[current].next;
+        }
+
+        sum
+    }
+}
+
+
+fn linked_list_set() -> (LinkedListSet<usize>, Vec<ListIndex>) {
+    let mut set = LinkedListSet::with_capacity(LIST_COUNT * ITEMS_PER_LIST);
+    let list_indices: Vec<ListIndex> = (0..LIST_COUNT).map(|_| set.new_list()).collect();
+    for item in 0..ITEMS_PER_LIST {
+        for list_index in list_indices.iter().copied() {
+            set.push_back(list_index, item);
+        }
+    }
+
+    (set, list_indices)
+}
+
+fn bench<F>(name: &str, operations: usize, mut f: F)
+where
+    F: FnMut()
+{
+    let mut best = Duration::MAX;
+    for _ in 0..ROUNDS {
+        let start = Instant::now();
+        f();
+        best = best.min(start.elapsed());
+    }
+    let per_operation = best.as_nanos() as f64 / operations as f64;
+
+    println!("{:<28} {:>10.2} ns/op", name, per_operation);
+}
+
+fn map_list_set(list_indices: &[ListIndex]) -> MapListSet {
+    let mut set = MapListSet::default();
+    set.nodes.reserve(LIST_COUNT * ITEMS_PER_LIST);
+    for list_index in list_indices.iter().copied() {
+        set.new_list(list_index);
+    }
+    for item in 0..ITEMS_PER_LIST {
+        for list_index in list_indices.iter().copied() {
+            set.push_back(list_index, item);
+        }
+    }
+
+    set
+}
+
+fn main() {
+    let (mut set, list_indices) = linked_list_set();
+    let mut map_set = map_list_set(&list_indices);
+
+    bench("push_back (map)", LIST_COUNT * ITEMS_PER_LIST, || {
+        black_box(map_list_set(&list_indices));
+    });
+    bench("push_back", LIST_COUNT * ITEMS_PER_LIST, || {
+        let (set, _) = linked_list_set();
+        black_box(set);
+    });
+    bench("len (map)", LIST_COUNT, || {
+        for list_index in list_indices.iter().copied() {
+            black_box(map_set.len(black_box(list_index)));
+        }
+    });
+    bench("len", LIST_COUNT, || {
+        for list_index in list_indices.iter().copied() {
+            black_box(set.len(black_box(list_index)));
+        }
+    });
+    bench("front (map)", LIST_COUNT, || {
+        for list_index in list_indices.iter().copied() {
+            black_box(map_set.front(black_box(list_index)));
+        }
+    });
+    bench("front", LIST_COUNT, || {
+        for list_index in list_indices.iter().copied() {
+            black_box(set.front(black_box(list_index)));
+        }
+    });
+    bench("contains_list (map)", LIST_COUNT, || {
+        for list_index in list_indices.iter().copied() {
+            black_box(map_set.contains_list(black_box(list_index)));
+        }
+    });
+    bench("contains_list", LIST_COUNT, || {
+        for list_index in list_indices.iter().copied() {
+            black_box(set.contains_list(black_box(list_index)));
+        }
+    });
+    bench("iter (map)", LIST_COUNT * ITEMS_PER_LIST, || {
+        for list_index in list_indices.iter().copied() {
+            black_box(map_set.sum(black_box(list_index)));
+        }
+    });
+    bench("iter", LIST_COUNT * ITEMS_PER_LIST, || {
+        for list_index in list_indices.iter().copied() {
+            black_box(set.iter(black_box(list_index)).sum::<usize>());
+        }
+    });
+    bench("push_back_pop_front (map)", LIST_COUNT, || {
+        for list_index in list_indices.iter().copied() {
+            let item = map_set.pop_front(list_index).unwrap();
+            map_set.push_back(list_index, item);
+        }
+    });
+    bench("push_back_pop_front", LIST_COUNT, || {
+        for list_index in list_indices.iter().copied() {
+            let item = set.pop_front(list_index).unwrap();
+            set.push_back(list_index, item);
+        }
+    });
+}
@@ -0,0 +1,117 @@
+//! Benchmarks comparing the node layouts of a linked list set: the default
+//! array-of-structures layout, which stores each item inline with its links,
+//! and the structure-of-arrays layout of `SoaLinkedListSet`, which stores the
+//! items in an array of their own.
+//!
+//! Both layouts are measured with items the size of a transform matrix, and
+//! with small `u32` items.
+//!
+//! Run with
+//! ```text
+//! cargo bench --bench node_layout
+//! ```
+extern crate list_set;
+
+
+use list_set::*;
+use std::hint::{
+    black_box,
+};
+use std::time::{
+    Duration,
+    Instant,
+};
+
+
+const LIST_COUNT: usize = 2_000;
+const ITEMS_PER_LIST: usize = 64;
+const ROUNDS: usize = 20;
+
+
+type Transform = [f32; 16];
+
+fn transform(value: usize) -> Transform {
+    let mut transform = [0_f32; 16];
+    transform[0] = value as f32;
+
+    transform
+}
+
+fn transform_value(transform: &Transform) -> f32 {
+    transform[0]
+}
+
+fn small(value: usize) -> u32 {
+    value as u32
+}
+
+fn small_value(value: &u32) -> f32 {
+    *value as f32
+}
+
+
+/// Build a set in each layout with the elements of the lists interleaved in
+/// storage, so that consecutive elements of a list are far apart.
+fn linked_list_sets<T>(item: fn(usize) -> T) -> (LinkedListSet<T>, SoaLinkedListSet<T>) {
+    let mut aos_set = LinkedListSet::with_capacity(LIST_COUNT * ITEMS_PER_LIST);
+    let mut soa_set: SoaLinkedListSet<T> = SoaLinkedListSet::default();
+    soa_set.reserve(LIST_COUNT * ITEMS_PER_LIST);
+    let list_indices: Vec<ListIndex> = (0..LIST_COUNT).map(|_| aos_set.new_list()).collect();
+    let soa_list_indices: Vec<ListIndex> = (0..LIST_COUNT).map(|_| soa_set.new_list()).collect();
+    for value in 0..ITEMS_PER_LIST {
+        for (list_index, soa_list_index) in list_indices.iter().zip(soa_list_indices.iter()) {
+            aos_set.push_back(*list_index, item(value));
+            soa_set.push_back(*soa_list_index, item(value));
+        }
+    }
+
+    (aos_set, soa_set)
+}
+
+fn bench<F>(name: &str, operations: usize, mut f: F)
+where
+    F: FnMut()
+{
+    let mut best = Duration::MAX;
+    for _ in 0..ROUNDS {
+        let start = Instant::now();
+        f();
+        best = best.min(start.elapsed());
+    }
+    let per_operation = best.as_nanos() as f64 / operations as f64;
+
+    println!("{:<32} {:>10.2} ns/op", name, per_operation);
+}
+
+fn bench_layouts<T>(label: &str, item: fn(usize) -> T, value: fn(&T) -> f32) {
+    let (aos_set, soa_set) = linked_list_sets(item);
+    let aos_list_indices: Vec<ListIndex> = aos_set.list_indices().collect();
+    let soa_list_indices: Vec<ListIndex> = soa_set.list_indices().collect();
+    let middle = ITEMS_PER_LIST / 2;
+
+    bench(&format!("aos get_at middle {}", label), LIST_COUNT * middle, || {
+        for list_index in aos_list_indices.iter().copied() {
+            black_box(aos_set.get_at(black_box(list_index), middle));
+        }
+    });
+    bench(&format!("soa get_at middle {}", label), LIST_COUNT * middle, || {
+        for list_index in soa_list_indices.iter().copied() {
+            black_box(soa_set.get_at(black_box(list_index), middle));
+        }
+    });
+    bench(&format!("aos iter {}", label), LIST_COUNT * ITEMS_PER_LIST, || {
+        for list_index in aos_list_indices.iter().copied() {
+            black_box(aos_set.iter(black_box(list_index)).map(value).sum::<f32>());
+        }
+    });
+    bench(&format!("soa iter {}", label), LIST_COUNT * ITEMS_PER_LIST, || {
+        for list_index in soa_list_indices.iter().copied() {
+            black_box(soa_set.iter(black_box(list_index)).map(value).sum::<f32>());
+        }
+    });
+}
+
+fn main() {
+    bench_layouts("transform", transform, transform_value);
+    bench_layouts("u32", small, small_value);
+}
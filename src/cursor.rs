@@ -0,0 +1,482 @@
+use crate::{
+    AosStorage,
+    IndexType,
+    LinkedListSet,
+    ListIndex,
+    NodeHandle,
+    NodeIndex,
+};
+use crate::storage::{
+    NodeStorage,
+};
+
+
+/// A cursor over a linked list inside of a linked list set.
+///
+/// A `Cursor` is like an iterator, except that it can freely seek
+/// back-and-forth. Cursors always rest between two elements in the list, and
+/// index in a logically circular way. To accommodate this, there is a "ghost"
+/// non-element that yields `None` between the back and the front of the
+/// list.
+///
+/// When created, cursors start at the front of the list, or the "ghost"
+/// non-element if the list is empty.
+#[derive(Clone, Debug)]
+pub struct Cursor<'a, T, Ix = u32, S = AosStorage<T, Ix>> {
+    /// The position of the cursor in the list. The ghost non-element has
+    /// position equal to the length of the list.
+    index: usize,
+    /// The node the cursor points to, or `NodeIndex::end()` if the cursor
+    /// points to the ghost non-element.
+    current: NodeIndex<Ix>,
+    /// The list the cursor traverses.
+    list_index: ListIndex<Ix>,
+    set: &'a LinkedListSet<T, Ix, S>,
+}
+
+impl<'a, T, Ix: IndexType, S: NodeStorage<T, Ix>> Cursor<'a, T, Ix, S> {
+    pub(crate) fn new(set: &'a LinkedListSet<T, Ix, S>, list_index: ListIndex<Ix>, current: NodeIndex<Ix>, index: usize) -> Self {
+        Self {
+            index: index,
+            current: current,
+            list_index: list_index,
+            set: set,
+        }
+    }
+
+    /// Returns the index of the linked list the cursor traverses.
+    #[inline]
+    pub fn list_index(&self) -> ListIndex<Ix> {
+        self.list_index
+    }
+
+    /// Returns the position of the cursor inside the linked list.
+    ///
+    /// Returns `None` if the cursor is currently pointing to the "ghost"
+    /// non-element.
+    pub fn index(&self) -> Option<usize> {
+        if self.current != NodeIndex::end() {
+            Some(self.index)
+        } else {
+            None
+        }
+    }
+
+    /// Move the cursor to the next element of the linked list.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this will
+    /// move it to the first element of the list. If it is pointing to the
+    /// last element of the list then this will move it to the "ghost"
+    /// non-element.
+    pub fn move_next(&mut self) {
+        if self.current == NodeIndex::end() {
+            self.current = self.set.get_list_unchecked(self.list_index).front;
+            self.index = 0;
+        } else {
+            self.current = self.set.get_node_unchecked(self.current).next();
+            self.index += 1;
+        }
+    }
+
+    /// Move the cursor to the previous element of the linked list.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this will
+    /// move it to the last element of the list. If it is pointing to the
+    /// first element of the list then this will move it to the "ghost"
+    /// non-element.
+    pub fn move_prev(&mut self) {
+        let list = self.set.get_list_unchecked(self.list_index);
+        if self.current == NodeIndex::end() {
+            self.current = list.back;
+            self.index = list.len().saturating_sub(1);
+        } else {
+            self.current = self.set.get_node_unchecked(self.current).previous();
+            self.index = if self.current == NodeIndex::end() {
+                list.len()
+            } else {
+                self.index - 1
+            };
+        }
+    }
+
+    /// Returns a reference to the element the cursor is currently pointing
+    /// to.
+    ///
+    /// Returns `None` if the cursor is currently pointing to the "ghost"
+    /// non-element.
+    pub fn current(&self) -> Option<&'a T> {
+        if self.current != NodeIndex::end() {
+            Some(self.set.get_item_unchecked(self.current))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a handle to the element the cursor is currently pointing to.
+    ///
+    /// Returns `None` if the cursor is currently pointing to the "ghost"
+    /// non-element.
+    pub fn current_handle(&self) -> Option<NodeHandle> {
+        if self.current != NodeIndex::end() {
+            Some(self.set.node_handle(self.current))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to the next element.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this returns
+    /// the first element of the list. If it is pointing to the last element
+    /// of the list then this returns `None`.
+    pub fn peek_next(&self) -> Option<&'a T> {
+        let next_index = if self.current == NodeIndex::end() {
+            self.set.get_list_unchecked(self.list_index).front
+        } else {
+            self.set.get_node_unchecked(self.current).next()
+        };
+
+        if next_index != NodeIndex::end() {
+            Some(self.set.get_item_unchecked(next_index))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to the previous element.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this returns
+    /// the last element of the list. If it is pointing to the first element
+    /// of the list then this returns `None`.
+    pub fn peek_prev(&self) -> Option<&'a T> {
+        let previous_index = if self.current == NodeIndex::end() {
+            self.set.get_list_unchecked(self.list_index).back
+        } else {
+            self.set.get_node_unchecked(self.current).previous()
+        };
+
+        if previous_index != NodeIndex::end() {
+            Some(self.set.get_item_unchecked(previous_index))
+        } else {
+            None
+        }
+    }
+
+    /// Provides a reference to the front element of the linked list the
+    /// cursor traverses, or `None` if the list is empty.
+    pub fn front(&self) -> Option<&'a T> {
+        self.set.front(self.list_index)
+    }
+
+    /// Provides a reference to the back element of the linked list the
+    /// cursor traverses, or `None` if the list is empty.
+    pub fn back(&self) -> Option<&'a T> {
+        self.set.back(self.list_index)
+    }
+}
+
+
+/// A cursor over a linked list inside of a linked list set with editing
+/// operations.
+///
+/// A `CursorMut` is like an iterator, except that it can freely seek
+/// back-and-forth, and can safely mutate the list during iteration. Cursors
+/// always rest between two elements in the list, and index in a logically
+/// circular way. To accommodate this, there is a "ghost" non-element that
+/// yields `None` between the back and the front of the list.
+///
+/// When created, cursors start at the front of the list, or the "ghost"
+/// non-element if the list is empty.
+#[derive(Debug)]
+pub struct CursorMut<'a, T, Ix = u32, S = AosStorage<T, Ix>> {
+    /// The position of the cursor in the list. The ghost non-element has
+    /// position equal to the length of the list.
+    index: usize,
+    /// The node the cursor points to, or `NodeIndex::end()` if the cursor
+    /// points to the ghost non-element.
+    current: NodeIndex<Ix>,
+    /// The list the cursor traverses.
+    list_index: ListIndex<Ix>,
+    set: &'a mut LinkedListSet<T, Ix, S>,
+}
+
+impl<'a, T, Ix: IndexType, S: NodeStorage<T, Ix>> CursorMut<'a, T, Ix, S> {
+    pub(crate) fn new(set: &'a mut LinkedListSet<T, Ix, S>, list_index: ListIndex<Ix>, current: NodeIndex<Ix>, index: usize) -> Self {
+        Self {
+            index: index,
+            current: current,
+            list_index: list_index,
+            set: set,
+        }
+    }
+
+    /// Returns the index of the linked list the cursor traverses.
+    #[inline]
+    pub fn list_index(&self) -> ListIndex<Ix> {
+        self.list_index
+    }
+
+    /// Returns the position of the cursor inside the linked list.
+    ///
+    /// Returns `None` if the cursor is currently pointing to the "ghost"
+    /// non-element.
+    pub fn index(&self) -> Option<usize> {
+        if self.current != NodeIndex::end() {
+            Some(self.index)
+        } else {
+            None
+        }
+    }
+
+    /// Move the cursor to the next element of the linked list.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this will
+    /// move it to the first element of the list. If it is pointing to the
+    /// last element of the list then this will move it to the "ghost"
+    /// non-element.
+    pub fn move_next(&mut self) {
+        if self.current == NodeIndex::end() {
+            self.current = self.set.get_list_unchecked(self.list_index).front;
+            self.index = 0;
+        } else {
+            self.current = self.set.get_node_unchecked(self.current).next();
+            self.index += 1;
+        }
+    }
+
+    /// Move the cursor to the previous element of the linked list.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this will
+    /// move it to the last element of the list. If it is pointing to the
+    /// first element of the list then this will move it to the "ghost"
+    /// non-element.
+    pub fn move_prev(&mut self) {
+        let list = self.set.get_list_unchecked(self.list_index);
+        if self.current == NodeIndex::end() {
+            self.current = list.back;
+            self.index = list.len().saturating_sub(1);
+        } else {
+            self.current = self.set.get_node_unchecked(self.current).previous();
+            self.index = if self.current == NodeIndex::end() {
+                list.len()
+            } else {
+                self.index - 1
+            };
+        }
+    }
+
+    /// Returns a mutable reference to the element the cursor is currently
+    /// pointing to.
+    ///
+    /// Returns `None` if the cursor is currently pointing to the "ghost"
+    /// non-element.
+    pub fn current(&mut self) -> Option<&mut T> {
+        if self.current != NodeIndex::end() {
+            Some(self.set.get_item_mut_unchecked(self.current))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a handle to the element the cursor is currently pointing to.
+    ///
+    /// Returns `None` if the cursor is currently pointing to the "ghost"
+    /// non-element.
+    pub fn current_handle(&self) -> Option<NodeHandle> {
+        if self.current != NodeIndex::end() {
+            Some(self.set.node_handle(self.current))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the next element.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this returns
+    /// the first element of the list. If it is pointing to the last element
+    /// of the list then this returns `None`.
+    pub fn peek_next(&mut self) -> Option<&mut T> {
+        let next_index = self.next_index();
+        if next_index != NodeIndex::end() {
+            Some(self.set.get_item_mut_unchecked(next_index))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a mutable reference to the previous element.
+    ///
+    /// If the cursor is pointing to the "ghost" non-element then this returns
+    /// the last element of the list. If it is pointing to the first element
+    /// of the list then this returns `None`.
+    pub fn peek_prev(&mut self) -> Option<&mut T> {
+        let previous_index = self.previous_index();
+        if previous_index != NodeIndex::end() {
+            Some(self.set.get_item_mut_unchecked(previous_index))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a read-only cursor pointing to the current element.
+    ///
+    /// The lifetime of the returned `Cursor` is bound to that of the
+    /// `CursorMut`, which means it cannot outlive the `CursorMut` and that
+    /// the `CursorMut` is frozen for the lifetime of the `Cursor`.
+    pub fn as_cursor(&self) -> Cursor<'_, T, Ix, S> {
+        Cursor::new(self.set, self.list_index, self.current, self.index)
+    }
+
+    /// Insert a new element into the linked list after the current one.
+    ///
+    /// If the cursor is pointing at the "ghost" non-element then the new
+    /// element is inserted at the front of the list. Returns a handle to the
+    /// new element.
+    pub fn insert_after(&mut self, item: T) -> NodeHandle {
+        let next_index = self.next_index();
+        let handle = self.set.insert_list_node(self.list_index, self.current, next_index, item);
+        if self.current == NodeIndex::end() {
+            self.index = self.set.len(self.list_index);
+        }
+
+        handle
+    }
+
+    /// Insert a new element into the linked list before the current one.
+    ///
+    /// If the cursor is pointing at the "ghost" non-element then the new
+    /// element is inserted at the back of the list. Returns a handle to the
+    /// new element.
+    pub fn insert_before(&mut self, item: T) -> NodeHandle {
+        let previous_index = self.previous_index();
+        let handle = self.set.insert_list_node(self.list_index, previous_index, self.current, item);
+        self.index += 1;
+
+        handle
+    }
+
+    /// Remove the current element from the linked list.
+    ///
+    /// The element that was removed is returned, and the cursor is moved to
+    /// point to the next element in the list. If the cursor is currently
+    /// pointing to the "ghost" non-element then no element is removed and
+    /// `None` is returned.
+    pub fn remove_current(&mut self) -> Option<T> {
+        if self.current == NodeIndex::end() {
+            return None;
+        }
+
+        let mut next_index = self.set.get_node_unchecked(self.current).next();
+        let item = self.set.remove_list_node_tracking(self.current, &mut next_index);
+        self.current = next_index;
+
+        Some(item)
+    }
+
+    /// Split the linked list into two after the current element.
+    ///
+    /// The elements after the current element move into a new linked list
+    /// in the same set, and the index of the new list is returned. If the
+    /// cursor is pointing at the "ghost" non-element then the entire
+    /// contents of the list are moved.
+    pub fn split_after(&mut self) -> ListIndex<Ix> {
+        let first_index = self.next_index();
+        let last_index = self.set.get_list_unchecked(self.list_index).back;
+        let new_list_index = self.set.split_list_nodes(self.list_index, first_index, last_index);
+        if self.current == NodeIndex::end() {
+            self.index = 0;
+        }
+
+        new_list_index
+    }
+
+    /// Split the linked list into two before the current element.
+    ///
+    /// The elements before the current element move into a new linked list
+    /// in the same set, and the index of the new list is returned. If the
+    /// cursor is pointing at the "ghost" non-element then the entire
+    /// contents of the list are moved.
+    pub fn split_before(&mut self) -> ListIndex<Ix> {
+        let first_index = self.set.get_list_unchecked(self.list_index).front;
+        let last_index = self.previous_index();
+        let new_list_index = if last_index != NodeIndex::end() {
+            self.set.split_list_nodes(self.list_index, first_index, last_index)
+        } else {
+            self.set.split_list_nodes(self.list_index, NodeIndex::end(), NodeIndex::end())
+        };
+        self.index = 0;
+
+        new_list_index
+    }
+
+    /// Move all the elements of another linked list in the set into this one
+    /// after the current element.
+    ///
+    /// The other list is left empty, but remains in the set. If the cursor
+    /// is pointing at the "ghost" non-element then the elements are inserted
+    /// at the front of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other_list_index` is the list the cursor traverses, or does
+    /// not point to a linked list in the set.
+    pub fn splice_after(&mut self, other_list_index: ListIndex<Ix>) {
+        assert_ne!(other_list_index, self.list_index, "cannot splice a linked list into itself");
+        let next_index = self.next_index();
+        self.set.splice_list_nodes(other_list_index, self.list_index, self.current, next_index);
+        if self.current == NodeIndex::end() {
+            self.index = self.set.len(self.list_index);
+        }
+    }
+
+    /// Move all the elements of another linked list in the set into this one
+    /// before the current element.
+    ///
+    /// The other list is left empty, but remains in the set. If the cursor
+    /// is pointing at the "ghost" non-element then the elements are inserted
+    /// at the back of the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other_list_index` is the list the cursor traverses, or does
+    /// not point to a linked list in the set.
+    pub fn splice_before(&mut self, other_list_index: ListIndex<Ix>) {
+        assert_ne!(other_list_index, self.list_index, "cannot splice a linked list into itself");
+        let other_length = self.set.len(other_list_index);
+        let previous_index = self.previous_index();
+        self.set.splice_list_nodes(other_list_index, self.list_index, previous_index, self.current);
+        self.index += other_length;
+    }
+
+    /// Provides a reference to the front element of the linked list the
+    /// cursor traverses, or `None` if the list is empty.
+    pub fn front(&self) -> Option<&T> {
+        self.set.front(self.list_index)
+    }
+
+    /// Provides a reference to the back element of the linked list the
+    /// cursor traverses, or `None` if the list is empty.
+    pub fn back(&self) -> Option<&T> {
+        self.set.back(self.list_index)
+    }
+
+    /// The position of the node after the cursor, treating the list as
+    /// circular through the ghost non-element.
+    fn next_index(&self) -> NodeIndex<Ix> {
+        if self.current == NodeIndex::end() {
+            self.set.get_list_unchecked(self.list_index).front
+        } else {
+            self.set.get_node_unchecked(self.current).next()
+        }
+    }
+
+    /// The position of the node before the cursor, treating the list as
+    /// circular through the ghost non-element.
+    fn previous_index(&self) -> NodeIndex<Ix> {
+        if self.current == NodeIndex::end() {
+            self.set.get_list_unchecked(self.list_index).back
+        } else {
+            self.set.get_node_unchecked(self.current).previous()
+        }
+    }
+}
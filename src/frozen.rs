@@ -0,0 +1,252 @@
+use crate::{
+    AosStorage,
+    DefragmentState,
+    IndexType,
+    LinkedListSet,
+    ListIndex,
+    ListIndices,
+    ListSetError,
+    ListSlots,
+    Node,
+    NodeHandle,
+    NodeHandleAllocator,
+    NodeIndex,
+};
+use crate::storage::{
+    NodeStorage,
+};
+use std::marker::{
+    PhantomData,
+};
+
+
+/// An immutable snapshot of a linked list set, with the elements of each
+/// linked list stored contiguously in one array.
+///
+/// The elements of the lists are laid out one list after another, in the same
+/// order as `list_indices`, so that each list is a slice of the array in
+/// compressed sparse row fashion. Reading a list is plain slice iteration.
+/// The list indices and node handles of the set the snapshot was frozen from
+/// remain valid for the snapshot, and for the set it thaws back into.
+#[derive(Clone, Debug)]
+pub struct FrozenListSet<T, Ix = u32, S = AosStorage<T, Ix>> {
+    /// The table tracking the positions of the items referred to by node
+    /// handles.
+    handles: NodeHandleAllocator<Ix>,
+    /// The headers of the linked lists. The front of each non-empty list is
+    /// the offset of its first element in `items`.
+    lists: ListSlots<T, Ix>,
+    /// The handle slot of each item in `items`.
+    item_handles: Vec<usize>,
+    /// The elements of every list, stored contiguously one list after
+    /// another.
+    items: Vec<T>,
+    /// The node storage of the set the snapshot thaws back into.
+    _marker: PhantomData<S>,
+}
+
+impl<T, Ix: IndexType, S: NodeStorage<T, Ix>> FrozenListSet<T, Ix, S> {
+    pub(crate) fn from_set(mut set: LinkedListSet<T, Ix, S>) -> Self {
+        set.compact();
+        let item_handles = (0..set.nodes.len())
+            .map(|index| set.nodes.node(index).handle())
+            .collect();
+
+        Self {
+            handles: set.handles,
+            lists: set.lists,
+            item_handles: item_handles,
+            items: set.nodes.into_items(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Convert the snapshot back into a mutable linked list set.
+    ///
+    /// The list indices and node handles of the snapshot remain valid for
+    /// the new set, and its storage starts out compact.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.extend(list_index, vec![1, 2, 3]);
+    /// let mut set = set.freeze().thaw();
+    /// set.push_back(list_index, 4);
+    /// let result: Vec<usize> = set.iter(list_index).copied().collect();
+    ///
+    /// assert_eq!(result, vec![1, 2, 3, 4]);
+    /// ```
+    pub fn thaw(self) -> LinkedListSet<T, Ix, S> {
+        let mut nodes = S::with_capacity(self.items.len());
+        let mut items = self.items.into_iter();
+        let mut item_handles = self.item_handles.into_iter();
+        for (index, slot) in self.lists.slots.iter().enumerate() {
+            if let Some(list) = slot.list.as_ref() {
+                let list_slot = Ix::new(index);
+                let start = nodes.len();
+                for (offset, handle) in item_handles.by_ref().take(list.len()).enumerate() {
+                    let mut node = Node::new(handle);
+                    if offset > 0 {
+                        node.previous = NodeIndex::new(start + offset - 1);
+                    }
+
+                    if offset + 1 < list.len() {
+                        node.next = NodeIndex::new(start + offset + 1);
+                    }
+
+                    nodes.push(node, list_slot, items.next().unwrap());
+                }
+            }
+        }
+
+        LinkedListSet {
+            handles: self.handles,
+            lists: self.lists,
+            nodes: nodes,
+            defragment: DefragmentState::default(),
+        }
+    }
+
+    /// Returns the elements of a linked list as a slice, in list order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `list_index` does not point to a linked list in the set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_indices = [set.new_list(), set.new_list()];
+    /// set.extend(list_indices[0], vec![1, 2, 3]);
+    /// set.push_front(list_indices[1], 5);
+    /// set.push_front(list_indices[1], 4);
+    /// let frozen = set.freeze();
+    ///
+    /// assert_eq!(frozen.as_slice(list_indices[0]), &[1, 2, 3]);
+    /// assert_eq!(frozen.as_slice(list_indices[1]), &[4, 5]);
+    /// ```
+    pub fn as_slice(&self, list_index: ListIndex<Ix>) -> &[T] {
+        match self.try_as_slice(list_index) {
+            Ok(slice) => slice,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Returns the elements of a linked list as a slice, in list order, or
+    /// an error if the list index does not point to a linked list in the set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// #     ListSetError,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_indices = [set.new_list(), set.new_list()];
+    /// set.extend(list_indices[0], vec![1, 2, 3]);
+    /// set.remove_list(list_indices[1]);
+    /// let frozen = set.freeze();
+    ///
+    /// assert_eq!(frozen.try_as_slice(list_indices[0]), Ok(&[1, 2, 3][..]));
+    /// assert_eq!(
+    ///     frozen.try_as_slice(list_indices[1]),
+    ///     Err(ListSetError::StaleHandle(list_indices[1]))
+    /// );
+    /// ```
+    pub fn try_as_slice(&self, list_index: ListIndex<Ix>) -> Result<&[T], ListSetError<Ix>> {
+        let list = self.lists.try_get(list_index)?;
+        if list.is_empty() {
+            return Ok(&[]);
+        }
+
+        let start = list.front.index();
+
+        Ok(&self.items[start..(start + list.len())])
+    }
+
+    /// Returns the elements of every linked list in the set as one slice.
+    ///
+    /// The lists appear one after another in the same order as
+    /// `list_indices`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_indices = [set.new_list(), set.new_list()];
+    /// set.extend(list_indices[1], vec![3, 4]);
+    /// set.extend(list_indices[0], vec![1, 2]);
+    /// let frozen = set.freeze();
+    ///
+    /// assert_eq!(frozen.items(), &[1, 2, 3, 4]);
+    /// ```
+    #[inline]
+    pub fn items(&self) -> &[T] {
+        &self.items
+    }
+
+    /// Get a reference to the element a node handle points to, or `None` if
+    /// the handle is no longer valid.
+    pub fn get(&self, handle: NodeHandle) -> Option<&T> {
+        self.handles.get(handle, self.lists.owner).map(|node_index| &self.items[node_index.index()])
+    }
+
+    /// Determine whether a list index points to a linked list in the set.
+    pub fn contains_list(&self, list_index: ListIndex<Ix>) -> bool {
+        self.lists.try_get(list_index).is_ok()
+    }
+
+    /// Returns the length of a linked list in the set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `list_index` does not point to a linked list in the set.
+    pub fn len(&self, list_index: ListIndex<Ix>) -> usize {
+        self.as_slice(list_index).len()
+    }
+
+    /// Returns the number of linked lists in the set.
+    #[inline]
+    pub fn list_count(&self) -> usize {
+        self.lists.len()
+    }
+
+    /// Returns the number of elements in the set over all linked lists.
+    #[inline]
+    pub fn node_count(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Determine whether the set contains no linked lists.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.lists.len() == 0
+    }
+
+    /// Returns an iterator over the indices of the linked lists in the set.
+    pub fn list_indices(&self) -> ListIndices<'_, T, Ix> {
+        ListIndices {
+            owner: self.lists.owner,
+            remaining: self.lists.len(),
+            iter: self.lists.slots.iter().enumerate(),
+        }
+    }
+}
+
@@ -37,85 +37,243 @@
 //! For examples of how to use the crate, there are ample examples in the linked 
 //! list set module documentation.
 //!
-extern crate fnv;
-
+mod cursor;
+mod frozen;
+mod sorted;
+mod storage;
+
+pub use cursor::{
+    Cursor,
+    CursorMut,
+};
+pub use frozen::{
+    FrozenListSet,
+};
+pub use sorted::{
+    SortedListSet,
+};
+pub use storage::{
+    AosStorage,
+    SoaStorage,
+};
 
-use fnv::{
-    FnvHashMap,
+use std::cmp::{
+    Ordering,
+    Reverse,
+};
+use std::collections::{
+    HashSet,
+    TryReserveError,
 };
 use std::fmt;
+use std::hash::{
+    Hash,
+};
 use std::marker::{
     PhantomData,
 };
+use std::mem;
+use std::ptr;
+use std::sync::atomic::{
+    self,
+    AtomicU32,
+};
+use storage::{
+    NodeStorage,
+    RawNodes,
+    Strided,
+};
+
+
+/// The integer type used to store positions inside the underlying storage of
+/// a linked list set.
+///
+/// Every node of a linked list set stores the positions of its neighbours, 
+/// of its handle slot, and of its linked list in the index type, so a 
+/// narrower index type shrinks the link overhead of every node, at the cost 
+/// of the number of nodes and lists the set can hold. The largest value of 
+/// the index type marks the end of a linked list, so a set with the index 
+/// type `Ix` holds at most `Ix::max().index()` nodes, and as many linked 
+/// lists, at a time.
+///
+/// # Safety
+///
+/// The unsafe iterator and storage code of a linked list set trusts the 
+/// conversions of the index type. For every `index` no greater than 
+/// `Ix::max().index()`, `Ix::new(index).index()` must return `index`, and 
+/// `Ix::max().index()` must not exceed `usize::MAX`.
+///
+/// # Example
+///
+/// ```
+/// # use list_set::{
+/// #     LinkedListSet,
+/// #     ListSetError,
+/// # };
+/// #
+/// let mut set: LinkedListSet<usize, u8> = LinkedListSet::default();
+/// let list_index = set.new_list();
+/// set.extend(list_index, 0..255);
+///
+/// assert_eq!(set.len(list_index), 255);
+/// assert_eq!(set.try_push_back(list_index, 255), Err(ListSetError::CapacityOverflow));
+/// ```
+pub unsafe trait IndexType: Copy + Default + Hash + Ord + fmt::Debug + 'static {
+    /// Convert a position in the underlying storage into an index.
+    ///
+    /// The position must fit in the index type.
+    fn new(index: usize) -> Self;
+
+    /// Convert an index into a position in the underlying storage.
+    fn index(&self) -> usize;
+
+    /// The largest value of the index type.
+    fn max() -> Self;
+}
+
+unsafe impl IndexType for usize {
+    #[inline(always)]
+    fn new(index: usize) -> Self {
+        index
+    }
+
+    #[inline(always)]
+    fn index(&self) -> usize {
+        *self
+    }
+
+    #[inline(always)]
+    fn max() -> Self {
+        usize::MAX
+    }
+}
+
+unsafe impl IndexType for u32 {
+    #[inline(always)]
+    fn new(index: usize) -> Self {
+        debug_assert!(index <= u32::MAX as usize, "index {} does not fit in a {}", index, stringify!(u32));
+        index as u32
+    }
+
+    #[inline(always)]
+    fn index(&self) -> usize {
+        *self as usize
+    }
+
+    #[inline(always)]
+    fn max() -> Self {
+        u32::MAX
+    }
+}
+
+unsafe impl IndexType for u16 {
+    #[inline(always)]
+    fn new(index: usize) -> Self {
+        debug_assert!(index <= u16::MAX as usize, "index {} does not fit in a {}", index, stringify!(u16));
+        index as u16
+    }
+
+    #[inline(always)]
+    fn index(&self) -> usize {
+        *self as usize
+    }
+
+    #[inline(always)]
+    fn max() -> Self {
+        u16::MAX
+    }
+}
+
+unsafe impl IndexType for u8 {
+    #[inline(always)]
+    fn new(index: usize) -> Self {
+        debug_assert!(index <= u8::MAX as usize, "index {} does not fit in a {}", index, stringify!(u8));
+        index as u8
+    }
+
+    #[inline(always)]
+    fn index(&self) -> usize {
+        *self as usize
+    }
+
+    #[inline(always)]
+    fn max() -> Self {
+        u8::MAX
+    }
+}
 
 
 /// An internal index describing the location of a linked list node inside the
 /// underlying storage of a linked list set.
 #[repr(transparent)]
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-struct NodeIndex(usize);
+struct NodeIndex<Ix>(Ix);
 
-impl NodeIndex {
+impl<Ix: IndexType> NodeIndex<Ix> {
+    #[inline]
     fn new(index: usize) -> Self {
         Self {
-            0: index,
+            0: Ix::new(index),
         }
     }
 
-    const fn end() -> Self {
+    #[inline]
+    fn end() -> Self {
         Self {
-            0: usize::MAX
+            0: <Ix as IndexType>::max()
         }
     }
+
+    /// Returns the position of the node in the underlying storage.
+    #[inline]
+    fn index(self) -> usize {
+        self.0.index()
+    }
 }
 
-/// A container that holds an element in a linked list.
-#[derive(Clone, Debug)]
-struct Node<T> {
-    /// The item in the linked list.
-    item: T,
-    /// The index of the linked list the node is a member of.
-    list: ListIndex,
+/// The link metadata of an element in a linked list. 
+///
+/// The node storage of the set keeps the item of the element and the 
+/// position of its linked list at the same position as the links.
+#[doc(hidden)]
+#[derive(Copy, Clone, Debug)]
+pub struct Node<Ix> {
     /// The position of the previous child list node inside the scene graph's 
     /// contiguous child list node storage.
-    previous: NodeIndex,
+    previous: NodeIndex<Ix>,
     /// The position of the next child list element inside the scene graph's
     /// contiguous child list node storage.
-    next: NodeIndex,
+    next: NodeIndex<Ix>,
+    /// The slot in the handle table that tracks the position of the node 
+    /// inside the node storage.
+    handle: Ix,
 }
 
-impl<T> Node<T> {
+impl<Ix: IndexType> Node<Ix> {
     /// Construct a new linked list node.
-    fn new(list_index: ListIndex, item: T) -> Self {
+    fn new(handle: usize) -> Self {
         Self {
-            item: item,
-            list: list_index,
             previous: NodeIndex::end(),
             next: NodeIndex::end(),
+            handle: Ix::new(handle),
         }
     }
 
-    /// Returns an immutable reference to the item stored in the node.
-    #[inline]
-    fn item(&self) -> &T {
-        &self.item
-    }
-
-    /// Returns an immutable reference to the item stored in the node.
+    /// Get the slot in the handle table that tracks the node.
     #[inline]
-    fn item_mut(&mut self) -> &mut T {
-        &mut self.item
+    fn handle(&self) -> usize {
+        self.handle.index()
     }
 
     /// Get the index of the previous item in the linked list.
     #[inline]
-    fn previous(&self) -> NodeIndex {
+    fn previous(&self) -> NodeIndex<Ix> {
         self.previous
     }
 
     /// Get the index of the next item in the linked list.
     #[inline]
-    fn next(&self) -> NodeIndex {
+    fn next(&self) -> NodeIndex<Ix> {
         self.next
     }
 }
@@ -123,11 +281,11 @@ impl<T> Node<T> {
 /// A doubly linked list whose nodes are owned by a contiguous backing store.
 #[doc(hidden)]
 #[derive(Debug)]
-pub struct LinkedList<T> {
+pub struct LinkedList<T, Ix = u32> {
     /// The index of the first node in the linked list.
-    front: NodeIndex,
+    front: NodeIndex<Ix>,
     /// The index of the last node in the linked list.
-    back: NodeIndex,
+    back: NodeIndex<Ix>,
     /// The number of elements in the linked list.
     length: usize,
     /// A marker indicating the type of them elements storage in the list.
@@ -136,9 +294,9 @@ pub struct LinkedList<T> {
     _marker: PhantomData<T>,
 }
 
-impl<T> LinkedList<T> {
+impl<T, Ix: IndexType> LinkedList<T, Ix> {
     /// Create a new empty list.
-    pub const fn new() -> Self {
+    pub fn new() -> Self {
         Self {
             front: NodeIndex::end(),
             back: NodeIndex::end(),
@@ -161,11 +319,17 @@ impl<T> LinkedList<T> {
     }
 }
 
-impl<T> Clone for LinkedList<T> {
+impl<T, Ix: IndexType> Default for LinkedList<T, Ix> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, Ix: Clone> Clone for LinkedList<T, Ix> {
     fn clone(&self) -> Self {
         Self {
-            front: self.front,
-            back: self.back,
+            front: self.front.clone(),
+            back: self.back.clone(),
             length: self.length,
             _marker: PhantomData,
         }
@@ -173,51 +337,179 @@ impl<T> Clone for LinkedList<T> {
 }
 
 /// A handle to a linked list inside the stored inside of a linked list set.
-#[repr(transparent)]
+///
+/// Besides the position of the linked list inside the set, a list index 
+/// records the generation of the list, and the identity of the set that 
+/// created it. This lets a linked list set reliably tell a live list 
+/// index apart from the index of a list that has since been removed, or 
+/// from an index created by a different set. Set identities are 32 bits 
+/// wide, so they start repeating once a program has created more than 
+/// `u32::MAX` sets, and only then can a set mistake the index of another 
+/// set for one of its own.
+///
+/// The position of the list is stored in the index type of the set, so a
+/// set with the index type `Ix` holds at most `Ix::max().index()` linked 
+/// lists at a time.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
-pub struct ListIndex(usize);
+pub struct ListIndex<Ix = u32> {
+    /// The position of the linked list inside the set.
+    index: Ix,
+    /// The generation of the linked list at that position.
+    generation: u32,
+    /// The identity of the set that created the list index.
+    owner: u32,
+}
 
-impl ListIndex {
+impl<Ix: IndexType> ListIndex<Ix> {
     /// Construct a new linked list index.
-    pub fn new(index: usize) -> Self {
+    #[inline]
+    fn new(index: usize, generation: u32, owner: u32) -> Self {
         Self {
-            0: index,
+            index: Ix::new(index),
+            generation: generation,
+            owner: owner,
         }
     }
 
-    pub const fn end() -> Self {
+    /// Construct a list index that never points to a linked list in any 
+    /// set.
+    pub fn end() -> Self {
         Self {
-            0: usize::MAX,
+            index: <Ix as IndexType>::max(),
+            generation: u32::MAX,
+            owner: u32::MAX,
         }
     }
+
+    /// Returns the position of the linked list inside the set.
+    #[inline]
+    fn slot(self) -> usize {
+        self.index.index()
+    }
+}
+
+impl<Ix: IndexType> fmt::Display for ListIndex<Ix> {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{}v{}", self.slot(), self.generation)
+    }
+}
+
+/// An error reported by the fallible operations on a linked list set.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ListSetError<Ix = u32> {
+    /// The list index was not created by this linked list set.
+    UnknownList(ListIndex<Ix>),
+    /// The list index points to a linked list that has been removed from the
+    /// set.
+    StaleHandle(ListIndex<Ix>),
+    /// A position inside a linked list is out of bounds.
+    IndexOutOfBounds {
+        /// The position that was requested.
+        index: usize,
+        /// The length of the linked list.
+        length: usize,
+    },
+    /// The memory allocator failed to allocate storage for the set, or the 
+    /// requested capacity exceeds the maximum size of the storage.
+    AllocationFailed,
+    /// The same list index appears more than once in a request for 
+    /// simultaneous mutable access to several linked lists.
+    DuplicateList(ListIndex<Ix>),
+    /// The number of nodes or linked lists in the set would exceed the 
+    /// number of positions the index type of the set can represent.
+    CapacityOverflow,
 }
 
-impl fmt::Display for ListIndex {
+impl<Ix: IndexType> fmt::Display for ListSetError<Ix> {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        write!(formatter, "{}", self.0)
+        match self {
+            ListSetError::UnknownList(list_index) => write!(
+                formatter, 
+                "list index {} does not belong to the linked list set", 
+                list_index
+            ),
+            ListSetError::StaleHandle(list_index) => write!(
+                formatter, 
+                "list index {} points to a linked list that has been removed from the set", 
+                list_index
+            ),
+            ListSetError::IndexOutOfBounds { index, length } => write!(
+                formatter, 
+                "position {} is out of bounds for a linked list of length {}", 
+                index, length
+            ),
+            ListSetError::AllocationFailed => write!(
+                formatter, 
+                "memory allocation failed"
+            ),
+            ListSetError::DuplicateList(list_index) => write!(
+                formatter, 
+                "list index {} appears more than once", 
+                list_index
+            ),
+            ListSetError::CapacityOverflow => write!(
+                formatter, 
+                "the number of nodes or linked lists exceeds the capacity of the index type of the set"
+            ),
+        }
+    }
+}
+
+impl<Ix: IndexType> std::error::Error for ListSetError<Ix> {}
+
+impl<Ix> From<TryReserveError> for ListSetError<Ix> {
+    fn from(_: TryReserveError) -> Self {
+        ListSetError::AllocationFailed
     }
 }
 
 #[derive(Clone, Debug)]
-pub struct ListIter<'a, T> {
-    current_front: NodeIndex,
-    current_back: NodeIndex,
-    position_front: usize,
-    position_back: usize,
-    list: LinkedList<T>,
-    nodes: &'a [Node<T>],
+pub struct ListIter<'a, T, Ix = u32> {
+    current_front: NodeIndex<Ix>,
+    current_back: NodeIndex<Ix>,
+    remaining: usize,
+    nodes: RawNodes<T, Ix>,
+    _marker: PhantomData<&'a T>,
+}
+
+impl<'a, T, Ix: IndexType> ListIter<'a, T, Ix> {
+    /// Construct an iterator over a linked list from a view of the node 
+    /// storage of its set.
+    ///
+    /// The caller must borrow the node storage for the lifetime `'a`.
+    fn from_raw_parts(list: &LinkedList<T, Ix>, nodes: RawNodes<T, Ix>) -> Self {
+        Self {
+            current_front: list.front,
+            current_back: list.back,
+            remaining: list.len(),
+            nodes: nodes,
+            _marker: PhantomData,
+        }
+    }
 }
 
-impl<'a, T: 'a> Iterator for ListIter<'a, T> {
+unsafe impl<'a, T: Sync, Ix: IndexType + Sync> Send for ListIter<'a, T, Ix> {}
+
+unsafe impl<'a, T: Sync, Ix: IndexType + Sync> Sync for ListIter<'a, T, Ix> {}
+
+impl<'a, T: 'a, Ix: IndexType> Iterator for ListIter<'a, T, Ix> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current_front != NodeIndex::end() {
-            let node = &self.nodes[self.current_front.0];
-            let item = node.item();
+        if self.remaining > 0 {
+            // # Safety
+            // The view of the node storage comes from a shared borrow that
+            // lasts for the lifetime of the iterator, and the view checks 
+            // every position against the length of the storage.
+            let (item, node) = unsafe {
+                let index = self.current_front.index();
+
+                (self.nodes.item(index), self.nodes.node(index))
+            };
 
             self.current_front = node.next();
-            self.position_front += 1;
+            self.remaining -= 1;
 
             Some(item)
         } else {
@@ -226,20 +518,23 @@ impl<'a, T: 'a> Iterator for ListIter<'a, T> {
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let remaining = self.list.len() - self.position_front;
-
-        (remaining, Some(remaining))
+        (self.remaining, Some(self.remaining))
     }
 }
 
-impl<'a, T: 'a> DoubleEndedIterator for ListIter<'a, T> {
+impl<'a, T: 'a, Ix: IndexType> DoubleEndedIterator for ListIter<'a, T, Ix> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.current_back != NodeIndex::end() {
-            let node = &self.nodes[self.current_back.0];
-            let item = node.item();
+        if self.remaining > 0 {
+            // # Safety
+            // See `next`.
+            let (item, node) = unsafe {
+                let index = self.current_back.index();
+
+                (self.nodes.item(index), self.nodes.node(index))
+            };
 
             self.current_back = node.previous();
-            self.position_back += 1;
+            self.remaining -= 1;
 
             Some(item)
         } else {
@@ -248,199 +543,4296 @@ impl<'a, T: 'a> DoubleEndedIterator for ListIter<'a, T> {
     }
 }
 
-impl<'a, T: 'a> ExactSizeIterator for ListIter<'a, T> {}
+impl<'a, T: 'a, Ix: IndexType> ExactSizeIterator for ListIter<'a, T, Ix> {}
 
 
 #[derive(Debug)]
-pub struct ListIterMut<'a, T> {
-    current_front: NodeIndex,
-    current_back: NodeIndex,
-    position_front: usize,
-    position_back: usize,
-    list: LinkedList<T>,
-    nodes: &'a mut [Node<T>],
+pub struct ListIterMut<'a, T, Ix = u32> {
+    list_index: ListIndex<Ix>,
+    current_front: NodeIndex<Ix>,
+    current_back: NodeIndex<Ix>,
+    remaining: usize,
+    nodes: RawNodes<T, Ix>,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T, Ix: IndexType> ListIterMut<'a, T, Ix> {
+    /// Construct a mutable iterator over a linked list from a mutable view 
+    /// of the node storage of its set.
+    ///
+    /// The caller must mutably borrow the node storage for the lifetime `'a`,
+    /// and must not construct two mutable iterators over the same list that 
+    /// are alive at the same time.
+    fn from_raw_parts(list_index: ListIndex<Ix>, list: &LinkedList<T, Ix>, nodes: RawNodes<T, Ix>) -> Self {
+        Self {
+            list_index: list_index,
+            current_front: list.front,
+            current_back: list.back,
+            remaining: list.len(),
+            nodes: nodes,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Check that the node at position `index` belongs to the iterated list.
+    ///
+    /// # Safety
+    ///
+    /// The node storage the iterator borrows must be alive.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the chain of the list ends before the length of the list is 
+    /// reached, or if the node belongs to a different list.
+    #[inline]
+    unsafe fn check_node(&self, index: usize) {
+        assert_ne!(
+            index, NodeIndex::<Ix>::end().index(), 
+            "linked list ended before reaching its length"
+        );
+        assert_eq!(
+            self.nodes.node_list(index), self.list_index.index, 
+            "linked list node belongs to a different list"
+        );
+    }
 }
 
-impl<'a, T: 'a> Iterator for ListIterMut<'a, T> {
+unsafe impl<'a, T: Send, Ix: IndexType + Sync> Send for ListIterMut<'a, T, Ix> {}
+
+unsafe impl<'a, T: Sync, Ix: IndexType + Sync> Sync for ListIterMut<'a, T, Ix> {}
+
+impl<'a, T: 'a, Ix: IndexType> Iterator for ListIterMut<'a, T, Ix> {
     type Item = &'a mut T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current_front != NodeIndex::end() {
+        if self.remaining > 0 {
+            let index = self.current_front.index();
             // # Safety
-            // The mutable reference to a node is only used once
+            // The mutable reference to an item is only used once
             // by the iterator, and nowhere else. This holds because the 
-            // node storage owns all of the nodes, and the iterator slice is a
-            // mutable reference to the node storage. Therefore, we can safely
-            // sidestep the borrow checker to get a mutable reference to each
-            // node inside the node storage.
-            let node = unsafe {
-                #[inline(always)]
-                unsafe fn bounded_by<A>(base_ptr: *mut A, len: usize, count: usize) -> bool {
-                    let peak_ptr = base_ptr.add(len);
-                    let ptr = base_ptr.add(count);
+            // node storage owns all of the items, and the view of the 
+            // storage comes from a mutable borrow of it. Therefore, we 
+            // can safely sidestep the borrow checker to get a mutable 
+            // reference to each item inside the node storage. The iterator 
+            // stops once the remaining count reaches zero, which happens when
+            // the front and back of the iterator meet, so no item is ever 
+            // yielded twice. Every node is checked to belong to the iterated 
+            // list before its item is handed out, so iterators over distinct
+            // lists visit disjoint sets of items and several of them can 
+            // coexist, even if the links of the set were left inconsistent 
+            // by a panic. The links are read apart from the items, so they 
+            // never alias an item that has been handed out.
+            let (item, node) = unsafe {
+                self.check_node(index);
+
+                (self.nodes.item_mut(index), self.nodes.node(index))
+            };
+
+            self.current_front = node.next();
+            self.remaining -= 1;
 
-                    (ptr <= peak_ptr) && (ptr >= base_ptr)
-                } 
+            Some(item)
+        } else {
+            None
+        }
+    }
 
-                let base_ptr = self.nodes.as_mut_ptr();
-                let count = self.current_front.0;
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
 
-                assert!(bounded_by(base_ptr, self.nodes.len(), count));
+impl<'a, T: 'a, Ix: IndexType> DoubleEndedIterator for ListIterMut<'a, T, Ix> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining > 0 {
+            let index = self.current_back.index();
+            // # Safety
+            // See `next`.
+            let (item, node) = unsafe {
+                self.check_node(index);
 
-                &mut *base_ptr.add(count)
+                (self.nodes.item_mut(index), self.nodes.node(index))
             };
-            let new_current_front = node.next();
-            let item = node.item_mut();
 
-            self.current_front = new_current_front;
-            self.position_front += 1;
+            self.current_back = node.previous();
+            self.remaining -= 1;
 
             Some(item)
         } else {
             None
         }
     }
+}
+
+impl<'a, T: 'a, Ix: IndexType> ExactSizeIterator for ListIterMut<'a, T, Ix> {}
+
+
+/// An iterator over every element of a linked list set in the order of the
+/// underlying storage, along with the index of the list each element belongs
+/// to.
+#[derive(Clone, Debug)]
+pub struct Nodes<'a, T, Ix = u32> {
+    lists: &'a ListSlots<T, Ix>,
+    node_lists: Strided<Ix>,
+    items: Strided<T>,
+    front: usize,
+    back: usize,
+    _marker: PhantomData<&'a T>,
+}
+
+impl<'a, T, Ix: IndexType> Nodes<'a, T, Ix> {
+    /// Construct an iterator over the node storage of a set from a view of 
+    /// the storage, and the list storage of the set.
+    ///
+    /// The caller must borrow the node storage for the lifetime `'a`.
+    fn from_raw_parts(lists: &'a ListSlots<T, Ix>, nodes: RawNodes<T, Ix>) -> Self {
+        Self {
+            lists: lists,
+            node_lists: nodes.node_lists,
+            items: nodes.items,
+            front: 0,
+            back: nodes.length,
+            _marker: PhantomData,
+        }
+    }
+}
+
+unsafe impl<'a, T: Sync, Ix: IndexType + Sync> Send for Nodes<'a, T, Ix> {}
+
+unsafe impl<'a, T: Sync, Ix: IndexType + Sync> Sync for Nodes<'a, T, Ix> {}
+
+impl<'a, T, Ix: IndexType> Iterator for Nodes<'a, T, Ix> {
+    type Item = (ListIndex<Ix>, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            // # Safety
+            // Every position between the front and the back of the iterator
+            // lies inside the node storage, which stays borrowed for the 
+            // lifetime of the iterator.
+            let node = unsafe {
+                (self.lists.list_index(*self.node_lists.get(self.front)), &*self.items.get(self.front))
+            };
+            self.front += 1;
+
+            Some(node)
+        } else {
+            None
+        }
+    }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let remaining = self.list.len() - self.position_front;
+        let remaining = self.back - self.front;
 
         (remaining, Some(remaining))
     }
 }
 
-impl<'a, T: 'a> DoubleEndedIterator for ListIterMut<'a, T> {
+impl<'a, T, Ix: IndexType> DoubleEndedIterator for Nodes<'a, T, Ix> {
     fn next_back(&mut self) -> Option<Self::Item> {
-        if self.current_back != NodeIndex::end() {
+        if self.front < self.back {
+            self.back -= 1;
+
             // # Safety
-            // The mutable reference to a node is only used once
-            // by the iterator, and nowhere else. This holds because the 
-            // node storage owns all of the nodes, and the iterator slice is a
-            // mutable reference to the node storage. Therefore, we can safely
-            // sidestep the borrow checker to get a mutable reference to each
-            // node inside the node storage.
-            let node = unsafe {
-                #[inline(always)]
-                unsafe fn bounded_by<A>(base_ptr: *mut A, len: usize, count: usize) -> bool {
-                    let peak_ptr = base_ptr.add(len);
-                    let ptr = base_ptr.add(count);
+            // See `next`.
+            unsafe {
+                Some((self.lists.list_index(*self.node_lists.get(self.back)), &*self.items.get(self.back)))
+            }
+        } else {
+            None
+        }
+    }
+}
 
-                    (ptr <= peak_ptr) && (ptr >= base_ptr)
-                } 
+impl<'a, T, Ix: IndexType> ExactSizeIterator for Nodes<'a, T, Ix> {}
 
-                let base_ptr = self.nodes.as_mut_ptr();
-                let count = self.current_back.0;
 
-                assert!(bounded_by(base_ptr, self.nodes.len(), count));
+/// A mutable iterator over every element of a linked list set in the order
+/// of the underlying storage, along with the index of the list each element
+/// belongs to.
+#[derive(Debug)]
+pub struct NodesMut<'a, T, Ix = u32> {
+    lists: &'a ListSlots<T, Ix>,
+    node_lists: Strided<Ix>,
+    items: Strided<T>,
+    front: usize,
+    back: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T, Ix: IndexType> NodesMut<'a, T, Ix> {
+    /// Construct a mutable iterator over the node storage of a set from a 
+    /// mutable view of the storage, and the list storage of the set.
+    ///
+    /// The caller must mutably borrow the node storage for the lifetime `'a`.
+    fn from_raw_parts(lists: &'a ListSlots<T, Ix>, nodes: RawNodes<T, Ix>) -> Self {
+        Self {
+            lists: lists,
+            node_lists: nodes.node_lists,
+            items: nodes.items,
+            front: 0,
+            back: nodes.length,
+            _marker: PhantomData,
+        }
+    }
+}
+
+unsafe impl<'a, T: Send, Ix: IndexType + Sync> Send for NodesMut<'a, T, Ix> {}
+
+unsafe impl<'a, T: Sync, Ix: IndexType + Sync> Sync for NodesMut<'a, T, Ix> {}
 
-                &mut *base_ptr.add(count)
+impl<'a, T, Ix: IndexType> Iterator for NodesMut<'a, T, Ix> {
+    type Item = (ListIndex<Ix>, &'a mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            // # Safety
+            // Every position between the front and the back of the iterator
+            // lies inside the node storage, which stays mutably borrowed for 
+            // the lifetime of the iterator, and every position is visited 
+            // once.
+            let node = unsafe {
+                (self.lists.list_index(*self.node_lists.get(self.front)), &mut *self.items.get(self.front))
             };
-            let new_current_back = node.previous();
-            let item = node.item_mut();
+            self.front += 1;
 
-            self.current_back = new_current_back;
-            self.position_back += 1;
+            Some(node)
+        } else {
+            None
+        }
+    }
 
-            Some(item)
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T, Ix: IndexType> DoubleEndedIterator for NodesMut<'a, T, Ix> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+
+            // # Safety
+            // See `next`.
+            unsafe {
+                Some((self.lists.list_index(*self.node_lists.get(self.back)), &mut *self.items.get(self.back)))
+            }
         } else {
             None
         }
     }
 }
 
-impl<'a, T: 'a> ExactSizeIterator for ListIterMut<'a, T> {}
+impl<'a, T, Ix: IndexType> ExactSizeIterator for NodesMut<'a, T, Ix> {}
 
 
-pub struct ListIndices<'a, T> {
-    iter: std::collections::hash_map::Keys<'a, ListIndex, LinkedList<T>>,
+/// A lazy iterator that removes and yields the elements of a linked list 
+/// matching a predicate.
+///
+/// The elements that do not match the predicate remain in the list, 
+/// including the elements the iterator has not yet reached when it is 
+/// dropped.
+pub struct ExtractIf<'a, T, F, Ix = u32, S = AosStorage<T, Ix>> 
+where
+    F: FnMut(&mut T) -> bool
+{
+    current: NodeIndex<Ix>,
+    predicate: F,
+    set: &'a mut LinkedListSet<T, Ix, S>,
 }
 
-impl<'a, T> Iterator for ListIndices<'a, T> {
-    type Item = ListIndex;
+impl<'a, T, F, Ix: IndexType, S: NodeStorage<T, Ix>> Iterator for ExtractIf<'a, T, F, Ix, S> 
+where
+    F: FnMut(&mut T) -> bool
+{
+    type Item = T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter.next().copied()
-    }
+        while self.current != NodeIndex::end() {
+            let current_index = self.current;
+            self.current = self.set.get_node_unchecked(current_index).next();
+            if (self.predicate)(self.set.get_item_mut_unchecked(current_index)) {
+                let item = self.set.remove_list_node_tracking(current_index, &mut self.current);
 
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        self.iter.size_hint()
+                return Some(item);
+            }
+        }
+
+        None
     }
 }
 
-impl<'a, T> ExactSizeIterator for ListIndices<'a, T> {}
 
+/// A draining iterator that removes and yields the elements of a linked 
+/// list in order.
+///
+/// Dropping the iterator removes the elements it has not yet yielded, so the
+/// list is always empty afterwards.
+pub struct Drain<'a, T, Ix: IndexType = u32, S: NodeStorage<T, Ix> = AosStorage<T, Ix>> {
+    list_index: ListIndex<Ix>,
+    remove_list: bool,
+    set: &'a mut LinkedListSet<T, Ix, S>,
+}
+
+impl<'a, T, Ix: IndexType, S: NodeStorage<T, Ix>> Iterator for Drain<'a, T, Ix, S> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.set.pop_front(self.list_index)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.set.len(self.list_index);
 
-#[derive(Clone, Debug, Default)]
-struct ListIndexAllocator {
-    current: usize,
+        (remaining, Some(remaining))
+    }
 }
 
-impl ListIndexAllocator {
-    const fn new() -> Self {
-        Self {
-            current: 0,
-        }
+impl<'a, T, Ix: IndexType, S: NodeStorage<T, Ix>> DoubleEndedIterator for Drain<'a, T, Ix, S> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.set.pop_back(self.list_index)
     }
+}
 
-    #[inline]
-    fn new_index(&mut self) -> ListIndex {
-        let next_index = ListIndex::new(self.current);
-        self.current += 1;
+impl<'a, T, Ix: IndexType, S: NodeStorage<T, Ix>> ExactSizeIterator for Drain<'a, T, Ix, S> {}
 
-        next_index
+impl<'a, T, Ix: IndexType, S: NodeStorage<T, Ix>> Drop for Drain<'a, T, Ix, S> {
+    fn drop(&mut self) {
+        self.set.clear(self.list_index);
+        if self.remove_list {
+            self.set.lists.remove(self.list_index);
+        }
     }
 }
 
 
-/// A collection of linked lists whose nodes are stored in an array-based 
-/// container.
-///
-/// The set allows pushing and popping elements to a particular linked list at 
-/// either end in constant time. Storing a set of linked lists in a linked 
-/// list set is more memory efficient, and makes better use of the CPU cache. 
-/// The ideal case for using a linked list set is in implementing compact 
-/// adjacency-list style graph data structures such as scene graphs.
+/// A draining iterator that removes and yields every element in a linked 
+/// list set, along with the index of the list each element belonged to.
 ///
-/// The linked lists stored in a `LinkedListSet` are accessed using their 
-/// `ListIndex` handle. The handle is guaranteed to be stable until the 
-/// list is explicitly removed from the set. That is, a `ListIndex` 
-/// pointing to a linked list in the set will continue to point to the same 
-/// linked list until the list is deleted from the set.
-#[derive(Clone, Debug, Default)]
-pub struct LinkedListSet<T> {
-    /// The allocator for generating new list handles.
-    alloc: ListIndexAllocator,
-    /// The collection of linked lists stored in the set.
-    lists: fnv::FnvHashMap<ListIndex, LinkedList<T>>,
-    /// The collection of all the nodes nodes of all the linked lists in the 
-    /// set. The nodes themselves can appear in any order inside the underlying 
-    /// storage.
-    nodes: Vec<Node<T>>,
+/// The elements are yielded in the order of the underlying storage, not in
+/// list order.
+pub struct DrainAll<'a, T, Ix = u32> {
+    lists: &'a ListSlots<T, Ix>,
+    node_lists: Strided<Ix>,
+    items: Strided<T>,
+    front: usize,
+    back: usize,
+    _marker: PhantomData<&'a mut T>,
 }
 
-impl<T> LinkedListSet<T> {
-    /// Create a new linked list set.
-    ///
-    /// # Example
-    ///
-    /// ```
-    /// # use list_set::{
-    /// #     LinkedListSet, 
+unsafe impl<'a, T: Send, Ix: IndexType + Sync> Send for DrainAll<'a, T, Ix> {}
+
+unsafe impl<'a, T: Sync, Ix: IndexType + Sync> Sync for DrainAll<'a, T, Ix> {}
+
+impl<'a, T, Ix: IndexType> Iterator for DrainAll<'a, T, Ix> {
+    type Item = (ListIndex<Ix>, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            // # Safety
+            // The node storage has handed its items over to the iterator, 
+            // and every position between the front and the back of the 
+            // iterator holds an item that has not been moved out yet.
+            let node = unsafe {
+                (self.lists.list_index(*self.node_lists.get(self.front)), ptr::read(self.items.get(self.front)))
+            };
+            self.front += 1;
+
+            Some(node)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T, Ix: IndexType> DoubleEndedIterator for DrainAll<'a, T, Ix> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front < self.back {
+            self.back -= 1;
+
+            // # Safety
+            // See `next`.
+            unsafe {
+                Some((self.lists.list_index(*self.node_lists.get(self.back)), ptr::read(self.items.get(self.back))))
+            }
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, T, Ix: IndexType> ExactSizeIterator for DrainAll<'a, T, Ix> {}
+
+impl<'a, T, Ix> Drop for DrainAll<'a, T, Ix> {
+    fn drop(&mut self) {
+        // Drop the items that have not been yielded. Should dropping an item
+        // panic, the rest of the items leak.
+        while self.front < self.back {
+            // # Safety
+            // See `next`.
+            unsafe {
+                ptr::drop_in_place(self.items.get(self.front));
+            }
+            self.front += 1;
+        }
+    }
+}
+
+
+/// An owning iterator over the elements of one linked list in a linked list
+/// set, in list order.
+#[derive(Debug)]
+pub struct ListIntoIter<T, Ix = u32, S = AosStorage<T, Ix>> {
+    list_index: ListIndex<Ix>,
+    set: LinkedListSet<T, Ix, S>,
+}
+
+impl<T, Ix: IndexType, S: NodeStorage<T, Ix>> Iterator for ListIntoIter<T, Ix, S> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.set.pop_front(self.list_index)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.set.len(self.list_index);
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T, Ix: IndexType, S: NodeStorage<T, Ix>> DoubleEndedIterator for ListIntoIter<T, Ix, S> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.set.pop_back(self.list_index)
+    }
+}
+
+impl<T, Ix: IndexType, S: NodeStorage<T, Ix>> ExactSizeIterator for ListIntoIter<T, Ix, S> {}
+
+impl<T, Ix, S> Clone for ListIntoIter<T, Ix, S> 
+where
+    T: Clone,
+    Ix: IndexType,
+    S: NodeStorage<T, Ix> + Clone
+{
+    fn clone(&self) -> Self {
+        let set = self.set.clone();
+
+        Self {
+            list_index: set.lists.rebind(self.list_index),
+            set: set,
+        }
+    }
+}
+
+
+/// An owning iterator over every element of a linked list set, along with 
+/// the index of the list each element belongs to.
+///
+/// The lists are visited in the same order as `LinkedListSet::list_indices`,
+/// and the elements of each list are yielded in list order.
+#[derive(Debug)]
+pub struct IntoIter<T, Ix = u32, S = AosStorage<T, Ix>> {
+    current: Option<ListIndex<Ix>>,
+    list_indices: std::vec::IntoIter<ListIndex<Ix>>,
+    set: LinkedListSet<T, Ix, S>,
+}
+
+impl<T, Ix: IndexType, S: NodeStorage<T, Ix>> Iterator for IntoIter<T, Ix, S> {
+    type Item = (ListIndex<Ix>, T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(list_index) = self.current {
+                if let Some(item) = self.set.pop_front(list_index) {
+                    return Some((list_index, item));
+                }
+            }
+
+            self.current = Some(self.list_indices.next()?);
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.set.node_count();
+
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T, Ix: IndexType, S: NodeStorage<T, Ix>> ExactSizeIterator for IntoIter<T, Ix, S> {}
+
+impl<T, Ix, S> Clone for IntoIter<T, Ix, S> 
+where
+    T: Clone,
+    Ix: IndexType,
+    S: NodeStorage<T, Ix> + Clone
+{
+    fn clone(&self) -> Self {
+        let set = self.set.clone();
+        let list_indices: Vec<ListIndex<Ix>> = self.list_indices
+            .as_slice()
+            .iter()
+            .map(|list_index| set.lists.rebind(*list_index))
+            .collect();
+
+        Self {
+            current: self.current.map(|list_index| set.lists.rebind(list_index)),
+            list_indices: list_indices.into_iter(),
+            set: set,
+        }
+    }
+}
+
+impl<T, Ix: IndexType, S: NodeStorage<T, Ix>> IntoIterator for LinkedListSet<T, Ix, S> {
+    type Item = (ListIndex<Ix>, T);
+    type IntoIter = IntoIter<T, Ix, S>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let list_indices: Vec<ListIndex<Ix>> = self.list_indices().collect();
+
+        IntoIter {
+            current: None,
+            list_indices: list_indices.into_iter(),
+            set: self,
+        }
+    }
+}
+
+
+/// An iterator over every linked list in a linked list set, yielding the 
+/// index of each list along with an iterator over its elements.
+#[derive(Clone, Debug)]
+pub struct ListsIter<'a, T, Ix = u32> {
+    owner: u32,
+    remaining: usize,
+    iter: std::iter::Enumerate<std::slice::Iter<'a, ListSlot<T, Ix>>>,
+    nodes: RawNodes<T, Ix>,
+}
+
+unsafe impl<'a, T: Sync, Ix: IndexType + Sync> Send for ListsIter<'a, T, Ix> {}
+
+unsafe impl<'a, T: Sync, Ix: IndexType + Sync> Sync for ListsIter<'a, T, Ix> {}
+
+impl<'a, T, Ix: IndexType> Iterator for ListsIter<'a, T, Ix> {
+    type Item = (ListIndex<Ix>, ListIter<'a, T, Ix>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (index, slot) in self.iter.by_ref() {
+            if let Some(list) = slot.list.as_ref() {
+                self.remaining -= 1;
+                let list_index = ListIndex::new(index, slot.generation, self.owner);
+                let iter = ListIter::from_raw_parts(list, self.nodes);
+
+                return Some((list_index, iter));
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T, Ix: IndexType> ExactSizeIterator for ListsIter<'a, T, Ix> {}
+
+
+/// An iterator over every linked list in a linked list set, yielding the 
+/// index of each list along with a mutable iterator over its elements.
+#[derive(Debug)]
+pub struct ListsIterMut<'a, T, Ix = u32> {
+    owner: u32,
+    remaining: usize,
+    iter: std::iter::Enumerate<std::slice::Iter<'a, ListSlot<T, Ix>>>,
+    nodes: RawNodes<T, Ix>,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<'a, T, Ix: IndexType> Iterator for ListsIterMut<'a, T, Ix> {
+    type Item = (ListIndex<Ix>, ListIterMut<'a, T, Ix>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (index, slot) in self.iter.by_ref() {
+            if let Some(list) = slot.list.as_ref() {
+                self.remaining -= 1;
+                let list_index = ListIndex::new(index, slot.generation, self.owner);
+                // Every list is yielded once, and distinct lists own 
+                // disjoint sets of items, so the mutable iterators never 
+                // alias each other.
+                let iter = ListIterMut::from_raw_parts(list_index, list, self.nodes);
+
+                return Some((list_index, iter));
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T, Ix: IndexType> ExactSizeIterator for ListsIterMut<'a, T, Ix> {}
+
+unsafe impl<'a, T: Send, Ix: IndexType + Sync> Send for ListsIterMut<'a, T, Ix> {}
+
+unsafe impl<'a, T: Sync, Ix: IndexType + Sync> Sync for ListsIterMut<'a, T, Ix> {}
+
+
+pub struct ListIndices<'a, T, Ix = u32> {
+    owner: u32,
+    remaining: usize,
+    iter: std::iter::Enumerate<std::slice::Iter<'a, ListSlot<T, Ix>>>,
+}
+
+impl<'a, T, Ix: IndexType> Iterator for ListIndices<'a, T, Ix> {
+    type Item = ListIndex<Ix>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for (index, slot) in self.iter.by_ref() {
+            if slot.list.is_some() {
+                self.remaining -= 1;
+
+                return Some(ListIndex::new(index, slot.generation, self.owner));
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T, Ix: IndexType> ExactSizeIterator for ListIndices<'a, T, Ix> {}
+
+
+/// The source of the identities of linked list sets. Every set takes a 
+/// fresh identity from here so that list indices from different sets never 
+/// compare equal. The counter wraps around after `u32::MAX` sets.
+static NEXT_SET_IDENTITY: AtomicU32 = AtomicU32::new(0);
+
+/// An entry in the list storage of a linked list set.
+#[derive(Clone, Debug)]
+struct ListSlot<T, Ix> {
+    /// The current generation of the slot. The generation changes every time 
+    /// the list occupying the slot is removed.
+    generation: u32,
+    /// The header of the linked list occupying the slot, if any.
+    list: Option<LinkedList<T, Ix>>,
+}
+
+/// A dense, vector-backed slot map storing the headers of the linked lists in
+/// a linked list set.
+///
+/// Looking up a linked list header from its list index is an array access
+/// followed by a check of the generation of the slot. The slots of removed 
+/// lists get reused by lists created afterwards.
+#[derive(Debug)]
+struct ListSlots<T, Ix> {
+    /// The identity stamped onto every list index the slot map creates.
+    owner: u32,
+    /// The slots containing the linked list headers.
+    slots: Vec<ListSlot<T, Ix>>,
+    /// The slots available for reuse.
+    free: Vec<usize>,
+    /// The number of occupied slots.
+    length: usize,
+}
+
+impl<T, Ix: IndexType> ListSlots<T, Ix> {
+    fn new() -> Self {
+        Self {
+            owner: NEXT_SET_IDENTITY.fetch_add(1, atomic::Ordering::Relaxed),
+            slots: Vec::new(),
+            free: Vec::new(),
+            length: 0,
+        }
+    }
+
+    /// Store a linked list header in a free slot, and return the list index 
+    /// pointing to it.
+    #[inline]
+    fn insert(&mut self, list: LinkedList<T, Ix>) -> ListIndex<Ix> {
+        self.length += 1;
+        if let Some(index) = self.free.pop() {
+            let slot = &mut self.slots[index];
+            slot.list = Some(list);
+
+            ListIndex::new(index, slot.generation, self.owner)
+        } else {
+            let index = self.slots.len();
+            self.slots.push(ListSlot {
+                generation: 0,
+                list: Some(list),
+            });
+
+            ListIndex::new(index, 0, self.owner)
+        }
+    }
+
+    /// Point a list index of another set at the slot with the same position 
+    /// and generation in this set.
+    #[inline]
+    fn rebind(&self, list_index: ListIndex<Ix>) -> ListIndex<Ix> {
+        ListIndex::new(list_index.slot(), list_index.generation, self.owner)
+    }
+
+    /// Construct the list index of the linked list occupying a slot.
+    #[inline]
+    fn list_index(&self, slot: Ix) -> ListIndex<Ix> {
+        ListIndex {
+            index: slot,
+            generation: self.slots[slot.index()].generation,
+            owner: self.owner,
+        }
+    }
+
+    /// Check that the slot map can hold `additional` more linked lists 
+    /// without running out of positions in the index type.
+    ///
+    /// The largest value of the index type marks a list index that never 
+    /// points to a linked list, so it is never the position of a slot.
+    fn check_capacity(&self, additional: usize) -> Result<(), ListSetError<Ix>> {
+        let slot_count = self.slots.len().checked_add(
+            additional.saturating_sub(self.free.len())
+        );
+        match slot_count {
+            Some(slot_count) if slot_count <= <Ix as IndexType>::max().index() => Ok(()),
+            _ => Err(ListSetError::CapacityOverflow),
+        }
+    }
+
+    /// Remove the linked list header a list index points to.
+    ///
+    /// Removing a list advances the generation of its slot, so the removed list 
+    /// index, and every copy of it, never compares equal to a list index 
+    /// handed out afterwards. A slot whose generation is exhausted is 
+    /// retired instead of being reused.
+    #[inline]
+    fn remove(&mut self, list_index: ListIndex<Ix>) -> Option<LinkedList<T, Ix>> {
+        self.get(list_index)?;
+        let slot = &mut self.slots[list_index.slot()];
+        let list = slot.list.take();
+        if slot.generation < u32::MAX {
+            slot.generation += 1;
+            self.free.push(list_index.slot());
+        }
+        self.length -= 1;
+
+        list
+    }
+
+    #[inline]
+    fn try_get(&self, list_index: ListIndex<Ix>) -> Result<&LinkedList<T, Ix>, ListSetError<Ix>> {
+        if list_index.owner != self.owner {
+            return Err(ListSetError::UnknownList(list_index));
+        }
+
+        match self.slots.get(list_index.slot()) {
+            Some(slot) if slot.generation == list_index.generation => {
+                slot.list.as_ref().ok_or(ListSetError::StaleHandle(list_index))
+            }
+            Some(_) => Err(ListSetError::StaleHandle(list_index)),
+            None => Err(ListSetError::UnknownList(list_index)),
+        }
+    }
+
+    #[inline]
+    fn try_get_mut(&mut self, list_index: ListIndex<Ix>) -> Result<&mut LinkedList<T, Ix>, ListSetError<Ix>> {
+        if list_index.owner != self.owner {
+            return Err(ListSetError::UnknownList(list_index));
+        }
+
+        match self.slots.get_mut(list_index.slot()) {
+            Some(slot) if slot.generation == list_index.generation => {
+                slot.list.as_mut().ok_or(ListSetError::StaleHandle(list_index))
+            }
+            Some(_) => Err(ListSetError::StaleHandle(list_index)),
+            None => Err(ListSetError::UnknownList(list_index)),
+        }
+    }
+
+    #[inline]
+    fn get(&self, list_index: ListIndex<Ix>) -> Option<&LinkedList<T, Ix>> {
+        self.try_get(list_index).ok()
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.length
+    }
+
+    /// The number of linked list headers the slot map can hold without 
+    /// reallocating.
+    #[inline]
+    fn capacity(&self) -> usize {
+        self.slots.capacity()
+    }
+
+    /// Reserve capacity for at least `additional` more linked list headers,
+    /// taking the free slots into account.
+    fn reserve(&mut self, additional: usize) {
+        self.slots.reserve(additional.saturating_sub(self.free.len()));
+    }
+
+    /// Try to reserve capacity for at least `additional` more linked list 
+    /// headers, taking the free slots into account.
+    fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.slots.try_reserve(additional.saturating_sub(self.free.len()))
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.slots.shrink_to_fit();
+        self.free.shrink_to_fit();
+    }
+
+    /// Remove every linked list header, keeping the generations of the slots.
+    fn clear(&mut self) {
+        self.free.clear();
+        for (index, slot) in self.slots.iter_mut().enumerate().rev() {
+            if slot.list.take().is_some() {
+                slot.generation = slot.generation.saturating_add(1);
+            }
+            if slot.generation < u32::MAX {
+                self.free.push(index);
+            }
+        }
+        self.length = 0;
+    }
+
+    /// Empty every linked list header without removing any of the lists.
+    fn clear_lists(&mut self) {
+        for slot in self.slots.iter_mut() {
+            if let Some(list) = slot.list.as_mut() {
+                *list = LinkedList::new();
+            }
+        }
+    }
+}
+
+impl<T: Clone, Ix: Clone> Clone for ListSlots<T, Ix> {
+    /// Clone the slot map under a fresh identity, so that a clone never 
+    /// accepts the list indices of the original, and the list indices 
+    /// created by the clone and the original never collide.
+    fn clone(&self) -> Self {
+        Self {
+            owner: NEXT_SET_IDENTITY.fetch_add(1, atomic::Ordering::Relaxed),
+            slots: self.slots.clone(),
+            free: self.free.clone(),
+            length: self.length,
+        }
+    }
+}
+
+impl<T, Ix: IndexType> Default for ListSlots<T, Ix> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+
+/// A stable handle to an element of a linked list stored inside of a linked
+/// list set.
+///
+/// Unlike the position of an element inside the underlying storage, a
+/// `NodeHandle` continues to point to the same element no matter how many
+/// other elements are removed from the set. A handle becomes invalid once its
+/// element is removed from the set, and an invalid handle never points to
+/// an element again, even if its storage gets reused. Like a list index, a 
+/// node handle records the identity of the set that created it, so a set 
+/// never mistakes the handle of another set for one of its own.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NodeHandle {
+    /// The slot in the handle table that tracks the element.
+    slot: usize,
+    /// The generation of the slot at the time the handle was created.
+    generation: u32,
+    /// The identity of the set that created the handle.
+    owner: u32,
+}
+
+impl fmt::Display for NodeHandle {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "{}v{}", self.slot, self.generation)
+    }
+}
+
+/// An entry in the handle table mapping a node handle to the current position
+/// of its node inside the node storage.
+#[derive(Copy, Clone, Debug)]
+struct HandleSlot<Ix> {
+    /// The current position of the node, or `NodeIndex::end()` if the slot
+    /// is free.
+    node: NodeIndex<Ix>,
+    /// The generation of the slot. The generation changes every time the
+    /// slot is freed so that old handles to the slot can be detected.
+    generation: u32,
+}
+
+#[derive(Clone, Debug, Default)]
+struct NodeHandleAllocator<Ix> {
+    /// The handle table.
+    slots: Vec<HandleSlot<Ix>>,
+    /// The slots in the handle table available for reuse.
+    free: Vec<usize>,
+}
+
+impl<Ix: IndexType> NodeHandleAllocator<Ix> {
+    const fn new() -> Self {
+        Self {
+            slots: Vec::new(),
+            free: Vec::new(),
+        }
+    }
+
+    /// Allocate a new handle for a node stored at position `node_index`, 
+    /// stamped with the identity `owner` of the set.
+    #[inline]
+    fn new_handle(&mut self, node_index: NodeIndex<Ix>, owner: u32) -> NodeHandle {
+        if let Some(slot) = self.free.pop() {
+            self.slots[slot].node = node_index;
+
+            NodeHandle {
+                slot: slot,
+                generation: self.slots[slot].generation,
+                owner: owner,
+            }
+        } else {
+            let slot = self.slots.len();
+            self.slots.push(HandleSlot {
+                node: node_index,
+                generation: 0,
+            });
+
+            NodeHandle {
+                slot: slot,
+                generation: 0,
+                owner: owner,
+            }
+        }
+    }
+
+    /// Release a handle slot, invalidating every handle pointing to it.
+    ///
+    /// A slot whose generation is exhausted is retired instead of being 
+    /// reused, so a stale handle can never point to a new node.
+    #[inline]
+    fn free_handle(&mut self, slot: usize) {
+        let handle_slot = &mut self.slots[slot];
+        handle_slot.node = NodeIndex::end();
+        if handle_slot.generation < u32::MAX {
+            handle_slot.generation += 1;
+            self.free.push(slot);
+        }
+    }
+
+    /// Record that the node tracked by a handle slot has moved to a new
+    /// position in the node storage.
+    #[inline]
+    fn relocate(&mut self, slot: usize, node_index: NodeIndex<Ix>) {
+        self.slots[slot].node = node_index;
+    }
+
+    /// Find the current position of the node a handle points to, or `None`
+    /// if the handle is no longer valid, or was not created by the set with
+    /// the identity `owner`.
+    #[inline]
+    fn get(&self, handle: NodeHandle, owner: u32) -> Option<NodeIndex<Ix>> {
+        if handle.owner != owner {
+            return None;
+        }
+
+        let handle_slot = self.slots.get(handle.slot)?;
+        if handle_slot.generation == handle.generation
+            && handle_slot.node != NodeIndex::end()
+        {
+            Some(handle_slot.node)
+        } else {
+            None
+        }
+    }
+
+    /// Reserve capacity for at least `additional` more handles, taking the free
+    /// slots into account.
+    fn reserve(&mut self, additional: usize) {
+        self.slots.reserve(additional.saturating_sub(self.free.len()));
+    }
+
+    /// Try to reserve capacity for at least `additional` more handles, taking 
+    /// the free slots into account.
+    fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.slots.try_reserve(additional.saturating_sub(self.free.len()))
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.slots.shrink_to_fit();
+        self.free.shrink_to_fit();
+    }
+
+    /// Release every handle slot at once.
+    fn clear(&mut self) {
+        self.free.clear();
+        for (slot, handle_slot) in self.slots.iter_mut().enumerate().rev() {
+            if handle_slot.node != NodeIndex::end() {
+                handle_slot.node = NodeIndex::end();
+                handle_slot.generation = handle_slot.generation.saturating_add(1);
+            }
+            if handle_slot.generation < u32::MAX {
+                self.free.push(slot);
+            }
+        }
+    }
+}
+
+
+/// The progress of an incremental defragmentation pass over a linked list 
+/// set.
+#[derive(Copy, Clone, Debug, Default)]
+struct DefragmentState {
+    /// The number of nodes at the start of the node storage that have been 
+    /// placed in their final positions.
+    position: usize,
+    /// The slot of the next linked list to compact once the current list is 
+    /// finished.
+    list_slot: usize,
+    /// The handle of the next node of the current linked list to place, or 
+    /// `None` if the current list is finished.
+    next: Option<NodeHandle>,
+}
+
+
+/// The progress of a merge sort over the nodes of a linked list.
+///
+/// The nodes of the list are always split into the merged nodes of the 
+/// current pass, linked from `head` to `tail`, the `left_length` unmerged 
+/// nodes of the left run starting at `left`, and the nodes linked from 
+/// `right` to the back of the list. Dropping the sort links the three parts 
+/// back together into the list, so the list stays valid even if a 
+/// comparison panics part way through a merge.
+struct ListSort<'a, T, Ix: IndexType, S: NodeStorage<T, Ix>> {
+    set: &'a mut LinkedListSet<T, Ix, S>,
+    list_index: ListIndex<Ix>,
+    head: NodeIndex<Ix>,
+    tail: NodeIndex<Ix>,
+    left: NodeIndex<Ix>,
+    left_length: usize,
+    right: NodeIndex<Ix>,
+}
+
+impl<'a, T, Ix: IndexType, S: NodeStorage<T, Ix>> ListSort<'a, T, Ix, S> {
+    /// Append a node to the merged nodes of the current pass.
+    fn push(&mut self, node_index: NodeIndex<Ix>) {
+        if self.tail != NodeIndex::end() {
+            self.set.get_node_mut_unchecked(self.tail).next = node_index;
+        } else {
+            self.head = node_index;
+        }
+
+        self.tail = node_index;
+    }
+}
+
+impl<'a, T, Ix: IndexType, S: NodeStorage<T, Ix>> Drop for ListSort<'a, T, Ix, S> {
+    fn drop(&mut self) {
+        let mut rest = self.right;
+        if self.left_length > 0 {
+            let mut last_index = self.left;
+            for _ in 1..self.left_length {
+                last_index = self.set.get_node_unchecked(last_index).next();
+            }
+
+            self.set.get_node_mut_unchecked(last_index).next = self.right;
+            rest = self.left;
+        }
+
+        if self.tail != NodeIndex::end() {
+            self.set.get_node_mut_unchecked(self.tail).next = rest;
+        } else {
+            self.head = rest;
+        }
+
+        let mut previous_index = NodeIndex::end();
+        let mut current_index = self.head;
+        while current_index != NodeIndex::end() {
+            let current_node = self.set.get_node_mut_unchecked(current_index);
+            current_node.previous = previous_index;
+            previous_index = current_index;
+            current_index = current_node.next();
+        }
+
+        let list = self.set.get_list_mut_unchecked(self.list_index);
+        list.front = self.head;
+        list.back = previous_index;
+    }
+}
+
+
+/// A collection of linked lists whose nodes are stored in an array-based 
+/// container.
+///
+/// The set allows pushing and popping elements to a particular linked list at 
+/// either end in constant time. Storing a set of linked lists in a linked 
+/// list set is more memory efficient, and makes better use of the CPU cache. 
+/// The ideal case for using a linked list set is in implementing compact 
+/// adjacency-list style graph data structures such as scene graphs.
+///
+/// The linked lists stored in a `LinkedListSet` are accessed using their 
+/// `ListIndex` handle. The handle is guaranteed to be stable until the 
+/// list is explicitly removed from the set. That is, a `ListIndex` 
+/// pointing to a linked list in the set will continue to point to the same 
+/// linked list until the list is deleted from the set.
+///
+/// The index type `Ix` sets the width of the positions each node stores, and 
+/// with it the maximum number of nodes in the set. See `IndexType`.
+///
+/// A clone of a linked list set is a separate set with its own identity, so 
+/// the list indices of the original do not point to the lists of the clone. 
+/// Use `list_indices` on the clone to obtain its list indices, which come out 
+/// in the same order as the list indices of the original.
+///
+/// The node storage `S` sets the memory layout of the nodes. The default, 
+/// `AosStorage`, stores each item inline with the links of its node. 
+/// `SoaStorage` stores the items in an array of their own, which keeps list 
+/// traversal from pulling large items into the cache, and allows borrowing 
+/// runs of a list as slices with `try_as_slices`. See `SoaLinkedListSet`.
+#[derive(Debug)]
+pub struct LinkedListSet<T, Ix = u32, S = AosStorage<T, Ix>> {
+    /// The table tracking the positions of the nodes referred to by node 
+    /// handles.
+    handles: NodeHandleAllocator<Ix>,
+    /// The collection of linked lists stored in the set.
+    lists: ListSlots<T, Ix>,
+    /// The collection of all the nodes nodes of all the linked lists in the 
+    /// set, along with their items and the indices of their lists. The nodes 
+    /// themselves can appear in any order inside the underlying storage.
+    nodes: S,
+    /// The progress of the current incremental defragmentation pass.
+    defragment: DefragmentState,
+}
+
+/// A linked list set that stores its nodes in the structure-of-arrays 
+/// layout.
+///
+/// # Example
+///
+/// ```
+/// # use list_set::{
+/// #     SoaLinkedListSet, 
+/// # };
+/// #
+/// let mut set: SoaLinkedListSet<[f32; 16]> = SoaLinkedListSet::default();
+/// let list_index = set.new_list();
+/// set.extend(list_index, vec![[1.0; 16], [2.0; 16]]);
+///
+/// assert_eq!(set.try_as_slices(list_index), Some((&[[1.0; 16], [2.0; 16]][..], &[][..])));
+/// ```
+pub type SoaLinkedListSet<T, Ix = u32> = LinkedListSet<T, Ix, SoaStorage<T, Ix>>;
+
+impl<T> LinkedListSet<T> {
+    /// Create a new linked list set with the default index type `u32`.
+    ///
+    /// Use `default` or `with_capacity` to create a set with a different 
+    /// index type or node storage.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet, 
+    /// # };
+    /// #
+    /// let mut set: LinkedListSet<usize> = LinkedListSet::new();
+    ///
+    /// assert!(set.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<T, Ix, S> Clone for LinkedListSet<T, Ix, S> 
+where
+    T: Clone,
+    Ix: IndexType,
+    S: NodeStorage<T, Ix> + Clone
+{
+    fn clone(&self) -> Self {
+        let lists = self.lists.clone();
+        let mut defragment = self.defragment;
+        defragment.next = defragment.next.map(|handle| NodeHandle {
+            owner: lists.owner,
+            ..handle
+        });
+
+        Self {
+            handles: self.handles.clone(),
+            lists: lists,
+            nodes: self.nodes.clone(),
+            defragment: defragment,
+        }
+    }
+}
+
+impl<T, Ix: IndexType, S: NodeStorage<T, Ix>> Default for LinkedListSet<T, Ix, S> {
+    fn default() -> Self {
+        Self {
+            handles: NodeHandleAllocator::new(),
+            lists: ListSlots::new(),
+            nodes: S::default(),
+            defragment: DefragmentState::default(),
+        }
+    }
+}
+
+impl<T, Ix: IndexType, S: NodeStorage<T, Ix>> LinkedListSet<T, Ix, S> {
+    /// Create a new linked list set with the specified capacity of linked 
+    /// list nodes.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet, 
+    /// #     SoaLinkedListSet, 
+    /// # };
+    /// #
+    /// let node_capacity = 3000; 
+    /// let mut set: LinkedListSet<usize> = LinkedListSet::with_capacity(
+    ///     node_capacity
+    /// );
+    ///
+    /// assert_eq!(set.node_capacity(), node_capacity);
+    ///
+    /// let narrow_set: LinkedListSet<usize, u16> = LinkedListSet::with_capacity(
+    ///     node_capacity
+    /// );
+    ///
+    /// assert_eq!(narrow_set.node_capacity(), node_capacity);
+    ///
+    /// let soa_set: SoaLinkedListSet<usize> = SoaLinkedListSet::with_capacity(
+    ///     node_capacity
+    /// );
+    ///
+    /// assert_eq!(soa_set.node_capacity(), node_capacity);
+    /// ```
+    pub fn with_capacity(node_capacity: usize) -> Self {
+        Self {
+            handles: NodeHandleAllocator {
+                slots: Vec::with_capacity(node_capacity),
+                free: Vec::new(),
+            },
+            lists: ListSlots::new(),
+            nodes: S::with_capacity(node_capacity),
+            defragment: DefragmentState::default(),
+        }
+    }
+
+    /// Create a new empty linked list in a linked list set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of linked lists would exceed the number of 
+    /// positions the index type of the set can represent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet, 
+    /// # };
+    /// #
+    /// let mut set: LinkedListSet<usize> = LinkedListSet::new();
+    ///
+    /// assert_eq!(set.list_count(), 0);
+    /// let _ = set.new_list();
+    /// assert_ne!(set.list_count(), 0);
+    /// ```
+    pub fn new_list(&mut self) -> ListIndex<Ix> {
+        if let Err(error) = self.lists.check_capacity(1) {
+            panic!("{}", error);
+        }
+
+        let new_list = LinkedList::new();
+
+        self.lists.insert(new_list)
+    }
+
+    /// Create a new empty linked list in a linked list set, or return an error 
+    /// if the set fails to allocate storage for the list, or if the number of 
+    /// linked lists would exceed the number of positions the index type of 
+    /// the set can represent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet, 
+    /// # };
+    /// #
+    /// let mut set: LinkedListSet<usize> = LinkedListSet::new();
+    /// let list_index = set.try_new_list().unwrap();
+    ///
+    /// assert!(set.contains_list(list_index));
+    /// ```
+    pub fn try_new_list(&mut self) -> Result<ListIndex<Ix>, ListSetError<Ix>> {
+        self.lists.check_capacity(1)?;
+        self.lists.try_reserve(1)?;
+
+        Ok(self.new_list())
+    }
+
+    /// Determine whether the entire linked list set is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet, 
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.extend(list_index, vec![0, 1, 2, 3, 4, 5]);
+    /// 
+    /// assert!(!set.is_empty());
+    /// assert_ne!(set.list_count(), 0);
+    /// assert_ne!(set.node_count(), 0);
+    /// assert!(!set.list_is_empty(list_index));
+    ///
+    /// set.clear_all();
+    ///
+    /// assert!(set.is_empty());
+    /// ```
+    pub fn is_empty(&self) -> bool {
+        self.lists.len() == 0
+    }
+
+    /// Get an immutable reference to a linked list with the given index 
+    /// unchecked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a linked list with the list index `list_index` does not
+    /// exist in the set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// #     ListIndex,
+    /// # };
+    /// #
+    /// let mut set: LinkedListSet<usize> = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// let removed_list_index = set.new_list();
+    /// set.remove_list(removed_list_index);
+    /// 
+    /// let ok_result = std::panic::catch_unwind(|| {
+    ///     set.get_list_unchecked(list_index)
+    /// });
+    /// assert!(ok_result.is_ok());
+    ///
+    /// let err_result = std::panic::catch_unwind(|| {
+    ///     set.get_list_unchecked(removed_list_index)
+    /// });
+    /// assert!(err_result.is_err());
+    /// ```
+    #[inline]
+    pub fn get_list_unchecked(&self, list_index: ListIndex<Ix>) -> &LinkedList<T, Ix> {
+        match self.lists.try_get(list_index) {
+            Ok(list) => list,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Get an mutable reference to a linked list with the given index 
+    /// unchecked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if a linked list with the list index `list_index` does not
+    /// exist in the set.
+    #[inline]
+    fn get_list_mut_unchecked(&mut self, list_index: ListIndex<Ix>) -> &mut LinkedList<T, Ix> {
+        match self.lists.try_get_mut(list_index) {
+            Ok(list) => list,
+            Err(error) => panic!("{}", error),
+        }
+    }
+
+    /// Get the linked list with the given index.
+    ///
+    /// Returns `None` if the list index does not point to a linked list in 
+    /// the set, either because the list has been removed, or because the 
+    /// list index belongs to a different set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// # };
+    /// #
+    /// let mut set: LinkedListSet<usize> = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// let removed_list_index = set.new_list();
+    /// set.remove_list(removed_list_index);
+    /// let mut other_set: LinkedListSet<usize> = LinkedListSet::new();
+    /// let foreign_list_index = other_set.new_list();
+    /// 
+    /// assert!(set.get_list(list_index).is_some());
+    /// assert!(set.get_list(removed_list_index).is_none());
+    /// assert!(set.get_list(foreign_list_index).is_none());
+    /// ```
+    #[inline]
+    pub fn get_list(&self, list_index: ListIndex<Ix>) -> Option<&LinkedList<T, Ix>> {
+        if self.contains_list(list_index) {
+            Some(self.get_list_unchecked(list_index))
+        } else {
+            None
+        }
+    }
+
+    /// Get the linked list with the given index, or an error describing why
+    /// the list index does not point to a linked list in the set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// #     ListSetError,
+    /// # };
+    /// #
+    /// let mut set: LinkedListSet<usize> = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// let removed_list_index = set.new_list();
+    /// set.remove_list(removed_list_index);
+    /// let mut other_set: LinkedListSet<usize> = LinkedListSet::new();
+    /// let foreign_list_index = other_set.new_list();
+    /// 
+    /// assert!(set.try_get_list(list_index).is_ok());
+    /// assert_eq!(
+    ///     set.try_get_list(removed_list_index).err(), 
+    ///     Some(ListSetError::StaleHandle(removed_list_index))
+    /// );
+    /// assert_eq!(
+    ///     set.try_get_list(foreign_list_index).err(), 
+    ///     Some(ListSetError::UnknownList(foreign_list_index))
+    /// );
+    /// ```
+    #[inline]
+    pub fn try_get_list(&self, list_index: ListIndex<Ix>) -> Result<&LinkedList<T, Ix>, ListSetError<Ix>> {
+        self.lists.try_get(list_index)
+    }
+
+    /// Get an immutable reference to a specific node from the linked list 
+    /// set unchecked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node index does not exist in the set.
+    ///
+    /// # Note
+    /// Node indices are not stable betwen linked list set mutations.
+    #[inline]
+    fn get_node_unchecked(&self, node_index: NodeIndex<Ix>) -> &Node<Ix> {
+        self.nodes.node(node_index.index())
+    }
+
+    /// Get a mutable reference to a specific node from the linked list set unchecked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node index does not exist in the set.
+    #[inline]
+    fn get_node_mut_unchecked(&mut self, node_index: NodeIndex<Ix>) -> &mut Node<Ix> {
+        self.nodes.node_mut(node_index.index())
+    }
+
+    /// Get the index of the linked list a specific node from the linked list 
+    /// set belongs to unchecked.
+    ///
+    /// A node only stores the position of its linked list, so the generation
+    /// of the list index comes from the list storage.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node index does not exist in the set.
+    #[inline]
+    fn get_node_list_unchecked(&self, node_index: NodeIndex<Ix>) -> ListIndex<Ix> {
+        self.lists.list_index(self.nodes.node_list(node_index.index()))
+    }
+
+    /// Get a mutable reference to the position of the linked list a specific 
+    /// node from the linked list set belongs to unchecked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node index does not exist in the set.
+    #[inline]
+    fn get_node_list_mut_unchecked(&mut self, node_index: NodeIndex<Ix>) -> &mut Ix {
+        self.nodes.node_list_mut(node_index.index())
+    }
+
+    /// Get an immutable reference to the item of a specific node from the 
+    /// linked list set unchecked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node index does not exist in the set.
+    #[inline]
+    fn get_item_unchecked(&self, node_index: NodeIndex<Ix>) -> &T {
+        self.nodes.item(node_index.index())
+    }
+
+    /// Get a mutable reference to the item of a specific node from the linked 
+    /// list set unchecked.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the node index does not exist in the set.
+    #[inline]
+    fn get_item_mut_unchecked(&mut self, node_index: NodeIndex<Ix>) -> &mut T {
+        self.nodes.item_mut(node_index.index())
+    }
+
+    /// Get mutable references to the items of two distinct nodes in the 
+    /// underlying storage unchecked.
+    #[inline]
+    fn get_item_pair_mut_unchecked(&mut self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) -> (&mut T, &mut T) {
+        self.nodes.item_pair_mut(a.index(), b.index())
+    }
+
+    /// Get the handle for the node at a specific position in the underlying 
+    /// storage.
+    #[inline]
+    fn node_handle(&self, node_index: NodeIndex<Ix>) -> NodeHandle {
+        let slot = self.get_node_unchecked(node_index).handle();
+
+        NodeHandle {
+            slot: slot,
+            generation: self.handles.slots[slot].generation,
+            owner: self.lists.owner,
+        }
+    }
+
+    /// Find the position in the underlying storage of the node at position 
+    /// `at` in a linked list.
+    ///
+    /// The list is traversed from whichever end is closer to `at`. Returns 
+    /// `NodeIndex::end()` if `at` falls outside the length of the list.
+    fn find_list_node(&self, list_index: ListIndex<Ix>, at: usize) -> NodeIndex<Ix> {
+        let list = self.get_list_unchecked(list_index);
+        if at >= list.len() {
+            return NodeIndex::end();
+        }
+
+        if at <= list.len() / 2 {
+            let mut current_index = list.front;
+            for _ in 0..at {
+                current_index = self.get_node_unchecked(current_index).next();
+            }
+
+            current_index
+        } else {
+            let mut current_index = list.back;
+            for _ in 0..(list.len() - 1 - at) {
+                current_index = self.get_node_unchecked(current_index).previous();
+            }
+
+            current_index
+        }
+    }
+
+    /// Returns the length of the linked list indexed by `list_index`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet, 
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// 
+    /// assert_eq!(set.len(list_index), 0);
+    ///
+    /// set.push_back(list_index, 321);
+    ///
+    /// assert_ne!(set.len(list_index), 0);
+    /// ```
+    pub fn len(&self, list_index: ListIndex<Ix>) -> usize {
+        self.get_list_unchecked(list_index).len()
+    }
+
+    /// Returns the length of the linked list indexed by `list_index`, or an 
+    /// error if the list index does not point to a linked list in the set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet, 
+    /// #     ListSetError,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.extend(list_index, vec![1, 2, 3]);
+    /// 
+    /// assert_eq!(set.try_len(list_index), Ok(3));
+    ///
+    /// set.remove_list(list_index);
+    ///
+    /// assert_eq!(set.try_len(list_index), Err(ListSetError::StaleHandle(list_index)));
+    /// ```
+    pub fn try_len(&self, list_index: ListIndex<Ix>) -> Result<usize, ListSetError<Ix>> {
+        let list = self.try_get_list(list_index)?;
+
+        Ok(list.len())
+    }
+
+    /// Determine whether a particular linked list in the set is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet, 
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// 
+    /// assert!(set.list_is_empty(list_index));
+    ///
+    /// set.push_back(list_index, 321);
+    ///
+    /// assert!(!set.list_is_empty(list_index));
+    /// ```
+    pub fn list_is_empty(&self, list_index: ListIndex<Ix>) -> bool {
+        self.get_list_unchecked(list_index).is_empty()
+    }
+
+    /// Determine whether a particular linked list in the set is empty, or 
+    /// return an error if the list index does not point to a linked list in 
+    /// the set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet, 
+    /// # };
+    /// #
+    /// let mut set: LinkedListSet<usize> = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// 
+    /// assert_eq!(set.try_list_is_empty(list_index), Ok(true));
+    ///
+    /// set.remove_list(list_index);
+    ///
+    /// assert!(set.try_list_is_empty(list_index).is_err());
+    /// ```
+    pub fn try_list_is_empty(&self, list_index: ListIndex<Ix>) -> Result<bool, ListSetError<Ix>> {
+        let list = self.try_get_list(list_index)?;
+
+        Ok(list.is_empty())
+    }
+
+    /// Returns the number of linked lists in the set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet, 
+    /// # };
+    /// #
+    /// let mut set: LinkedListSet<usize> = LinkedListSet::new();
+    /// let list_index0 = set.new_list();
+    /// let list_index1 = set.new_list();
+    /// let list_index2 = set.new_list();
+    /// 
+    /// assert_eq!(set.list_count(), 3);
+    /// ```
+    pub fn list_count(&self) -> usize {
+        self.lists.len()
+    }
+
+    /// Returns the maximum number items across all lists combined that the
+    /// set can currently hold.
+    ///
+    /// The function returns the number of items the collection currently *can* hold,
+    /// *not* the number of items the function currently holds. For that, use the
+    /// `node_count` function.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet, 
+    /// # };
+    /// #
+    /// let expected_node_capacity = 3000;
+    /// let mut set: LinkedListSet<usize> = LinkedListSet::with_capacity(
+    ///     expected_node_capacity
+    /// );
+    /// 
+    /// assert_eq!(set.node_capacity(), expected_node_capacity);
+    /// ```
+    pub fn node_capacity(&self) -> usize {
+        self.nodes.capacity()
+    }
+
+    /// Returns the number of linked lists the set can hold without 
+    /// reallocating.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet, 
+    /// # };
+    /// #
+    /// let mut set: LinkedListSet<usize> = LinkedListSet::new();
+    /// set.reserve_lists(10);
+    /// 
+    /// assert!(set.list_capacity() >= 10);
+    /// ```
+    pub fn list_capacity(&self) -> usize {
+        self.lists.capacity()
+    }
+
+    /// Check that the set can hold `additional` more nodes without running 
+    /// out of positions in its index type.
+    ///
+    /// The largest value of the index type marks the end of a linked list, 
+    /// so it is never the position of a node, nor the slot of a handle.
+    fn check_node_capacity(&self, additional: usize) -> Result<(), ListSetError<Ix>> {
+        let max_node_count = <Ix as IndexType>::max().index();
+        let node_count = self.nodes.len().checked_add(additional);
+        let handle_count = self.handles.slots.len().checked_add(
+            additional.saturating_sub(self.handles.free.len())
+        );
+        match (node_count, handle_count) {
+            (Some(node_count), Some(handle_count)) 
+                if node_count <= max_node_count && handle_count <= max_node_count => Ok(()),
+            _ => Err(ListSetError::CapacityOverflow),
+        }
+    }
+
+    /// Reserve capacity for at least `additional` more items across all lists
+    /// combined.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows `usize`, or exceeds the number of 
+    /// nodes the index type of the set can represent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet, 
+    /// # };
+    /// #
+    /// let mut set: LinkedListSet<usize> = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.push_back(list_index, 1);
+    /// set.reserve(10);
+    /// 
+    /// assert!(set.node_capacity() >= 11);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        if let Err(error) = self.check_node_capacity(additional) {
+            panic!("{}", error);
+        }
+
+        self.nodes.reserve(additional);
+        self.handles.reserve(additional);
+    }
+
+    /// Try to reserve capacity for at least `additional` more items across 
+    /// all lists combined.
+    ///
+    /// Returns an error instead of aborting if the allocator fails to 
+    /// allocate the storage, or the new capacity overflows `usize`. Returns 
+    /// `ListSetError::CapacityOverflow` if the new capacity exceeds the 
+    /// number of nodes the index type of the set can represent.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet, 
+    /// #     ListSetError,
+    /// # };
+    /// #
+    /// let mut set: LinkedListSet<usize, usize> = LinkedListSet::default();
+    /// 
+    /// assert!(set.try_reserve(10).is_ok());
+    /// assert!(set.node_capacity() >= 10);
+    /// assert_eq!(set.try_reserve(usize::MAX - 1), Err(ListSetError::AllocationFailed));
+    ///
+    /// let mut set: LinkedListSet<usize> = LinkedListSet::new();
+    ///
+    /// assert_eq!(set.try_reserve(usize::MAX), Err(ListSetError::CapacityOverflow));
+    /// ```
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), ListSetError<Ix>> {
+        self.check_node_capacity(additional)?;
+        self.nodes.try_reserve(additional)?;
+        self.handles.try_reserve(additional)?;
+
+        Ok(())
+    }
+
+    /// Reserve capacity for at least `additional` more linked lists.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the new capacity overflows `usize`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet, 
+    /// # };
+    /// #
+    /// let mut set: LinkedListSet<usize> = LinkedListSet::new();
+    /// set.new_list();
+    /// set.reserve_lists(10);
+    /// 
+    /// assert!(set.list_capacity() >= 11);
+    /// ```
+    pub fn reserve_lists(&mut self, additional: usize) {
+        self.lists.reserve(additional);
+    }
+
+    /// Try to reserve capacity for at least `additional` more linked lists.
+    ///
+    /// Returns an error instead of aborting if the allocator fails to 
+    /// allocate the storage, or the new capacity overflows `usize`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet, 
+    /// #     ListSetError,
+    /// # };
+    /// #
+    /// let mut set: LinkedListSet<usize> = LinkedListSet::new();
+    /// 
+    /// assert!(set.try_reserve_lists(10).is_ok());
+    /// assert!(set.list_capacity() >= 10);
+    /// assert_eq!(set.try_reserve_lists(usize::MAX), Err(ListSetError::AllocationFailed));
+    /// ```
+    pub fn try_reserve_lists(&mut self, additional: usize) -> Result<(), ListSetError<Ix>> {
+        self.lists.try_reserve(additional)?;
+
+        Ok(())
+    }
+
+    /// Shrink the capacity of the underlying storage of the set as much as
+    /// possible.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet, 
+    /// # };
+    /// #
+    /// let mut set: LinkedListSet<usize> = LinkedListSet::with_capacity(100);
+    /// let list_index = set.new_list();
+    /// set.extend(list_index, vec![1, 2, 3]);
+    /// set.shrink_to_fit();
+    /// 
+    /// assert!(set.node_capacity() >= 3);
+    /// assert!(set.node_capacity() < 100);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.nodes.shrink_to_fit();
+        self.handles.shrink_to_fit();
+        self.lists.shrink_to_fit();
+    }
+
+    /// Reorder the underlying storage of the set so that the elements of 
+    /// each linked list sit next to each other in list order.
+    ///
+    /// The lists are laid out one after another in the same order as 
+    /// `list_indices`. After compaction, iterating over a list is a linear 
+    /// scan through memory. List indices and node handles remain valid. The
+    /// compaction runs in linear time in the number of nodes and lists, and
+    /// abandons any incremental pass started by `defragment`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_indices = [set.new_list(), set.new_list()];
+    /// for item in 0..6 {
+    ///     set.push_front(list_indices[item % 2], item);
+    /// }
+    /// set.compact();
+    /// let storage_order: Vec<usize> = set.nodes().map(|(_, item)| *item).collect();
+    ///
+    /// assert_eq!(storage_order, vec![4, 2, 0, 5, 3, 1]);
+    /// assert_eq!(set.iter(list_indices[0]).copied().collect::<Vec<usize>>(), vec![4, 2, 0]);
+    /// ```
+    pub fn compact(&mut self) {
+        self.defragment = DefragmentState::default();
+        while self.defragment_step() {}
+        self.defragment = DefragmentState::default();
+    }
+
+    /// Run part of an incremental compaction of the underlying storage of 
+    /// the set, placing at most `budget` nodes in their final positions.
+    ///
+    /// The set remembers how far the pass has progressed, so calling 
+    /// `defragment` repeatedly, for instance once per frame, eventually has
+    /// the same effect as `compact`. Returns `true` once the pass has 
+    /// finished, after which the next call starts a new pass. If the set is
+    /// modified between calls, the pass still finishes, but the storage may 
+    /// not be fully compact until the next pass.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_indices = [set.new_list(), set.new_list()];
+    /// for item in 0..6 {
+    ///     set.push_back(list_indices[item % 2], item);
+    /// }
+    ///
+    /// while !set.defragment(2) {}
+    ///
+    /// let storage_order: Vec<usize> = set.nodes().map(|(_, item)| *item).collect();
+    ///
+    /// assert_eq!(storage_order, vec![0, 2, 4, 1, 3, 5]);
+    /// ```
+    pub fn defragment(&mut self, budget: usize) -> bool {
+        for _ in 0..budget {
+            if !self.defragment_step() {
+                self.defragment = DefragmentState::default();
+
+                return true;
+            }
+        }
+
+        false
+    }
+
+    /// Freeze the set into an immutable snapshot that stores the elements of
+    /// each linked list in one contiguous slice.
+    ///
+    /// The set is compacted first, so freezing takes linear time in the 
+    /// number of nodes. The list indices and node handles of the set remain
+    /// valid for the snapshot. Use `FrozenListSet::thaw` to get a mutable 
+    /// set back.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.push_back(list_index, 2);
+    /// set.push_front(list_index, 1);
+    /// set.push_back(list_index, 3);
+    /// let frozen = set.freeze();
+    ///
+    /// assert_eq!(frozen.as_slice(list_index), &[1, 2, 3]);
+    /// ```
+    pub fn freeze(self) -> FrozenListSet<T, Ix, S> {
+        FrozenListSet::from_set(self)
+    }
+
+    /// Returns the number of linked list nodes in the set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet, 
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index0 = set.new_list();
+    /// let list_index1 = set.new_list();
+    /// let list_index2 = set.new_list();
+    /// set.extend(list_index0, vec![0, 1, 2, 3, 4, 5]);
+    /// set.extend(list_index1, vec![6, 7, 8, 9, 10, 11]);
+    /// set.extend(list_index2, vec![12, 13, 14, 15, 16, 17]);
+    ///
+    /// assert_eq!(
+    ///     set.node_count(), 
+    ///     set.len(list_index0) + set.len(list_index1) + set.len(list_index2)
+    /// );
+    /// ```
+    pub fn node_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Determine whether a linked list contains a particular item.
+    ///
+    /// Returns `true` if the linked list contains an instance of `item`, and 
+    /// `false` if the linked list does not contain and instance of `item`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,  
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.extend(
+    ///     list_index, 
+    ///     vec![String::from("spam"), String::from("eggs")]
+    /// );
+    ///
+    /// assert!(set.contains(list_index, &String::from("spam")));
+    /// assert!(set.contains(list_index, &String::from("eggs")));
+    /// assert!(!set.contains(list_index, &String::from("pancakes")));
+    /// ```
+    ///
+    /// If you do not have an instance of `&T`, but just an `&U` such that `T: Borrow<U>`
+    /// (for example, `String: Borrow<str>`), you can also use the `any()` function from
+    /// the iterator trait.
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,  
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.extend(
+    ///     list_index, 
+    ///     vec![String::from("spam"), String::from("eggs")]
+    /// );
+    ///
+    /// assert!(set.iter(list_index).any(|item| item == "spam"));
+    /// assert!(set.iter(list_index).any(|item| item == "eggs"));
+    /// assert!(!set.iter(list_index).any(|item| item == "pancakes"));
+    /// ```
+    pub fn contains(&self, list_index: ListIndex<Ix>, item: &T) -> bool
+    where
+        T: PartialEq<T>
+    {
+        let mut current_index = self.get_list_unchecked(list_index).front;
+        while current_index != NodeIndex::end() {
+            if self.get_item_unchecked(current_index) == item {
+                return true;
+            } else {
+                current_index = self.get_node_unchecked(current_index).next();
+            }
+        }
+
+        false
+    }
+
+    /// Determine whether a linked list contains a particular item, or return
+    /// an error if the list index does not point to a linked list in the set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,  
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.extend(list_index, vec![1, 2, 3]);
+    ///
+    /// assert_eq!(set.try_contains(list_index, &2), Ok(true));
+    /// assert_eq!(set.try_contains(list_index, &4), Ok(false));
+    ///
+    /// set.remove_list(list_index);
+    ///
+    /// assert!(set.try_contains(list_index, &2).is_err());
+    /// ```
+    pub fn try_contains(&self, list_index: ListIndex<Ix>, item: &T) -> Result<bool, ListSetError<Ix>>
+    where
+        T: PartialEq<T>
+    {
+        self.try_get_list(list_index)?;
+
+        Ok(self.contains(list_index, item))
+    }
+
+    /// Determine whether a linked list set contains a linked list with the 
+    /// index given by `list_index`.
+    ///
+    /// Returns `false` for the index of a linked list that has been removed
+    /// from the set, and for a list index created by a different set.
+    ///
+    /// # Example
+    /// 
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// #     ListIndex,
+    /// # };
+    /// #
+    /// let mut set: LinkedListSet<usize> = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// let removed_list_index = set.new_list();
+    /// set.remove_list(removed_list_index);
+    /// let mut other_set: LinkedListSet<usize> = LinkedListSet::new();
+    /// let foreign_list_index = other_set.new_list();
+    /// 
+    /// assert!(set.contains_list(list_index));
+    /// assert!(!set.contains_list(removed_list_index));
+    /// assert!(!set.contains_list(foreign_list_index));
+    /// assert!(!set.contains_list(ListIndex::end()));
+    /// ```
+    pub fn contains_list(&self, list_index: ListIndex<Ix>) -> bool {
+        self.lists.get(list_index).is_some()
+    }
+
+    /// Provide an immutable forward iterator for a linked list with the 
+    /// index `list_index` inside the linked list set.
+    ///
+    /// # Example
+    /// 
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// let expected = vec![
+    ///     String::from("spam"), 
+    ///     String::from("eggs"), 
+    ///     String::from("pancakes")
+    /// ];
+    /// set.extend(list_index, expected.iter().cloned());
+    /// 
+    /// assert!(set.iter(list_index).enumerate().all(|(i, item_i)| {
+    ///     item_i == &expected[i]
+    /// }));
+    /// ```
+    pub fn iter(&self, list_index: ListIndex<Ix>) -> ListIter<'_, T, Ix> {
+        ListIter::from_raw_parts(self.get_list_unchecked(list_index), self.nodes.raw())
+    }
+
+    /// Provide an immutable forward iterator for a linked list with the 
+    /// index `list_index` inside the linked list set, or an error if the list
+    /// index does not point to a linked list in the set.
+    ///
+    /// # Example
+    /// 
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.extend(list_index, vec![1, 2, 3]);
+    /// 
+    /// let result: Vec<i32> = set.try_iter(list_index).unwrap().copied().collect();
+    ///
+    /// assert_eq!(result, vec![1, 2, 3]);
+    ///
+    /// set.remove_list(list_index);
+    ///
+    /// assert!(set.try_iter(list_index).is_err());
+    /// ```
+    pub fn try_iter(&self, list_index: ListIndex<Ix>) -> Result<ListIter<'_, T, Ix>, ListSetError<Ix>> {
+        self.try_get_list(list_index)?;
+
+        Ok(self.iter(list_index))
+    }
+
+    /// Provide a mutable forward iterator for a linked list with the 
+    /// index `list_index` inside the linked list set.
+    ///
+    /// # Example
+    /// 
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// let data = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
+    /// set.extend(list_index, data);
+    /// 
+    /// for item in set.iter_mut(list_index) {
+    ///     *item = 2 * (*item);
+    /// }
+    ///
+    /// let expected = vec![2, 4, 6, 8, 10, 12, 14, 16, 18, 20];
+    /// let result: Vec<usize> = set.iter(list_index).copied().collect();
+    ///
+    /// assert_eq!(result, expected);
+    /// ```
+    pub fn iter_mut(&mut self, list_index: ListIndex<Ix>) -> ListIterMut<'_, T, Ix> {
+        let nodes = self.nodes.raw_mut();
+
+        ListIterMut::from_raw_parts(list_index, self.get_list_unchecked(list_index), nodes)
+    }
+
+    /// Provide a mutable forward iterator for a linked list with the 
+    /// index `list_index` inside the linked list set, or an error if the list
+    /// index does not point to a linked list in the set.
+    ///
+    /// # Example
+    /// 
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.extend(list_index, vec![1, 2, 3]);
+    /// 
+    /// for item in set.try_iter_mut(list_index).unwrap() {
+    ///     *item *= 2;
+    /// }
+    /// let result: Vec<i32> = set.iter(list_index).copied().collect();
+    ///
+    /// assert_eq!(result, vec![2, 4, 6]);
+    ///
+    /// set.remove_list(list_index);
+    ///
+    /// assert!(set.try_iter_mut(list_index).is_err());
+    /// ```
+    pub fn try_iter_mut(&mut self, list_index: ListIndex<Ix>) -> Result<ListIterMut<'_, T, Ix>, ListSetError<Ix>> {
+        self.try_get_list(list_index)?;
+
+        Ok(self.iter_mut(list_index))
+    }
+
+    /// Provide an iterator over every linked list in the set, yielding the 
+    /// index of each list along with an iterator over its elements.
+    ///
+    /// The lists are visited in the same order as `list_indices`, without 
+    /// looking up each list index separately.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_indices = [set.new_list(), set.new_list()];
+    /// set.extend(list_indices[0], vec![1, 2, 3]);
+    /// set.extend(list_indices[1], vec![4, 5]);
+    /// let sums: Vec<usize> = set.iter_lists().map(|(_, iter)| iter.sum()).collect();
+    ///
+    /// assert_eq!(sums, vec![6, 9]);
+    /// ```
+    pub fn iter_lists(&self) -> ListsIter<'_, T, Ix> {
+        ListsIter {
+            owner: self.lists.owner,
+            remaining: self.lists.len(),
+            iter: self.lists.slots.iter().enumerate(),
+            nodes: self.nodes.raw(),
+        }
+    }
+
+    /// Provide an iterator over every linked list in the set, yielding the 
+    /// index of each list along with a mutable iterator over its elements.
+    ///
+    /// The mutable iterators of different lists can be held at the same 
+    /// time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_indices = [set.new_list(), set.new_list()];
+    /// set.extend(list_indices[0], vec![1, 2, 3]);
+    /// set.extend(list_indices[1], vec![4, 5]);
+    /// for (i, (_, iter)) in set.iter_lists_mut().enumerate() {
+    ///     for item in iter {
+    ///         *item += 10 * (i + 1);
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(set.iter(list_indices[0]).copied().collect::<Vec<usize>>(), vec![11, 12, 13]);
+    /// assert_eq!(set.iter(list_indices[1]).copied().collect::<Vec<usize>>(), vec![24, 25]);
+    /// ```
+    pub fn iter_lists_mut(&mut self) -> ListsIterMut<'_, T, Ix> {
+        let nodes = self.nodes.raw_mut();
+
+        ListsIterMut {
+            owner: self.lists.owner,
+            remaining: self.lists.len(),
+            iter: self.lists.slots.iter().enumerate(),
+            nodes: nodes,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Check that every list index points to a linked list in the set, and 
+    /// that no linked list appears more than once.
+    fn check_disjoint_lists(&self, list_indices: &[ListIndex<Ix>]) -> Result<(), ListSetError<Ix>> {
+        let mut seen = HashSet::with_capacity(list_indices.len());
+        for list_index in list_indices.iter().copied() {
+            self.try_get_list(list_index)?;
+            if !seen.insert(list_index) {
+                return Err(ListSetError::DuplicateList(list_index));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Provide mutable iterators over several distinct linked lists in the 
+    /// set at the same time.
+    ///
+    /// Returns an error if any list index does not point to a linked list in
+    /// the set, or if the same list appears more than once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// #     ListSetError,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_indices = [set.new_list(), set.new_list()];
+    /// set.extend(list_indices[0], vec![1, 2, 3]);
+    /// set.extend(list_indices[1], vec![4, 5, 6]);
+    /// let [source, target] = set.get_disjoint_mut(list_indices).unwrap();
+    /// for (a, b) in source.zip(target) {
+    ///     std::mem::swap(a, b);
+    /// }
+    ///
+    /// assert_eq!(set.iter(list_indices[0]).copied().collect::<Vec<usize>>(), vec![4, 5, 6]);
+    /// assert_eq!(set.iter(list_indices[1]).copied().collect::<Vec<usize>>(), vec![1, 2, 3]);
+    /// assert_eq!(
+    ///     set.get_disjoint_mut([list_indices[0], list_indices[0]]).err(), 
+    ///     Some(ListSetError::DuplicateList(list_indices[0]))
+    /// );
+    /// ```
+    pub fn get_disjoint_mut<const N: usize>(&mut self, list_indices: [ListIndex<Ix>; N]) -> Result<[ListIterMut<'_, T, Ix>; N], ListSetError<Ix>> {
+        self.check_disjoint_lists(&list_indices)?;
+        let nodes = self.nodes.raw_mut();
+        let set = &*self;
+
+        Ok(list_indices.map(|list_index| {
+            ListIterMut::from_raw_parts(list_index, set.get_list_unchecked(list_index), nodes)
+        }))
+    }
+
+    /// Provide mutable iterators over several distinct linked lists in the 
+    /// set at the same time, in the same order as the list indices.
+    ///
+    /// Returns an error if any list index does not point to a linked list in
+    /// the set, or if the same list appears more than once.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_indices: Vec<_> = (0..4).map(|_| set.new_list()).collect();
+    /// for list_index in list_indices.iter().copied() {
+    ///     set.extend(list_index, vec![1, 2]);
+    /// }
+    /// let iters = set.iter_mut_many(&list_indices[1..3]).unwrap();
+    /// for (i, iter) in iters.into_iter().enumerate() {
+    ///     iter.for_each(|item| *item *= 10 + i);
+    /// }
+    ///
+    /// assert_eq!(set.iter(list_indices[0]).copied().collect::<Vec<usize>>(), vec![1, 2]);
+    /// assert_eq!(set.iter(list_indices[1]).copied().collect::<Vec<usize>>(), vec![10, 20]);
+    /// assert_eq!(set.iter(list_indices[2]).copied().collect::<Vec<usize>>(), vec![11, 22]);
+    /// ```
+    pub fn iter_mut_many(&mut self, list_indices: &[ListIndex<Ix>]) -> Result<Vec<ListIterMut<'_, T, Ix>>, ListSetError<Ix>> {
+        self.check_disjoint_lists(list_indices)?;
+        let nodes = self.nodes.raw_mut();
+        let set = &*self;
+        let iters = list_indices
+            .iter()
+            .map(|list_index| ListIterMut::from_raw_parts(*list_index, set.get_list_unchecked(*list_index), nodes))
+            .collect();
+
+        Ok(iters)
+    }
+
+    /// Provide an iterator over every element in the set, along with the 
+    /// index of the list each element belongs to.
+    ///
+    /// The elements are visited in the order of the underlying storage rather
+    /// than in list order, so the iteration walks memory sequentially. Use 
+    /// this for passes over the whole set where the order of the elements 
+    /// does not matter.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_indices = [set.new_list(), set.new_list()];
+    /// set.extend(list_indices[0], vec![1, 2, 3]);
+    /// set.extend(list_indices[1], vec![4, 5]);
+    /// let total: usize = set.nodes().map(|(_, item)| *item).sum();
+    /// let in_second_list = set.nodes().filter(|(l, _)| *l == list_indices[1]).count();
+    ///
+    /// assert_eq!(total, 15);
+    /// assert_eq!(in_second_list, 2);
+    /// ```
+    pub fn nodes(&self) -> Nodes<'_, T, Ix> {
+        Nodes::from_raw_parts(&self.lists, self.nodes.raw())
+    }
+
+    /// Provide a mutable iterator over every element in the set, along with 
+    /// the index of the list each element belongs to.
+    ///
+    /// The elements are visited in the order of the underlying storage rather
+    /// than in list order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_indices = [set.new_list(), set.new_list()];
+    /// set.extend(list_indices[0], vec![1, 2, 3]);
+    /// set.extend(list_indices[1], vec![4, 5]);
+    /// for (list_index, item) in set.nodes_mut() {
+    ///     if list_index == list_indices[1] {
+    ///         *item *= 10;
+    ///     }
+    /// }
+    /// let result: Vec<usize> = set.iter(list_indices[1]).copied().collect();
+    ///
+    /// assert_eq!(result, vec![40, 50]);
+    /// ```
+    pub fn nodes_mut(&mut self) -> NodesMut<'_, T, Ix> {
+        NodesMut::from_raw_parts(&self.lists, self.nodes.raw_mut())
+    }
+
+    /// Apply a function to every element in the set, in the order of the 
+    /// underlying storage.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_indices = [set.new_list(), set.new_list()];
+    /// set.extend(list_indices[0], vec![1, 2, 3]);
+    /// set.extend(list_indices[1], vec![4, 5]);
+    /// set.for_each_mut(|item| *item += 1);
+    ///
+    /// assert_eq!(set.iter(list_indices[0]).copied().collect::<Vec<usize>>(), vec![2, 3, 4]);
+    /// assert_eq!(set.iter(list_indices[1]).copied().collect::<Vec<usize>>(), vec![5, 6]);
+    /// ```
+    pub fn for_each_mut<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&mut T)
+    {
+        for (_, item) in self.nodes_mut() {
+            f(item);
+        }
+    }
+
+    /// Replace every element in the set with the result of applying a 
+    /// function to it, in the order of the underlying storage.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.extend(list_index, vec![1, 2, 3]);
+    /// set.map_in_place(|item| item * item);
+    /// let result: Vec<usize> = set.iter(list_index).copied().collect();
+    ///
+    /// assert_eq!(result, vec![1, 4, 9]);
+    /// ```
+    pub fn map_in_place<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&T) -> T
+    {
+        for (_, item) in self.nodes_mut() {
+            *item = f(item);
+        }
+    }
+
+    /// Provide a cursor pointing to the front element of a linked list in the
+    /// set.
+    ///
+    /// The cursor points to the "ghost" non-element if the list is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.extend(list_index, vec![1, 2, 3]);
+    /// let mut cursor = set.cursor_front(list_index);
+    ///
+    /// assert_eq!(cursor.current(), Some(&1));
+    /// assert_eq!(cursor.peek_next(), Some(&2));
+    ///
+    /// cursor.move_next();
+    /// cursor.move_next();
+    ///
+    /// assert_eq!(cursor.current(), Some(&3));
+    /// assert_eq!(cursor.index(), Some(2));
+    ///
+    /// cursor.move_next();
+    ///
+    /// assert_eq!(cursor.current(), None);
+    /// ```
+    pub fn cursor_front(&self, list_index: ListIndex<Ix>) -> Cursor<'_, T, Ix, S> {
+        let front = self.get_list_unchecked(list_index).front;
+
+        Cursor::new(self, list_index, front, 0)
+    }
+
+    /// Provide a cursor pointing to the back element of a linked list in the
+    /// set.
+    ///
+    /// The cursor points to the "ghost" non-element if the list is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.extend(list_index, vec![1, 2, 3]);
+    /// let mut cursor = set.cursor_back(list_index);
+    ///
+    /// assert_eq!(cursor.current(), Some(&3));
+    /// assert_eq!(cursor.index(), Some(2));
+    ///
+    /// cursor.move_prev();
+    ///
+    /// assert_eq!(cursor.current(), Some(&2));
+    /// ```
+    pub fn cursor_back(&self, list_index: ListIndex<Ix>) -> Cursor<'_, T, Ix, S> {
+        let list = self.get_list_unchecked(list_index);
+        let back = list.back;
+        let index = list.len().saturating_sub(1);
+
+        Cursor::new(self, list_index, back, index)
+    }
+
+    /// Provide a cursor with editing operations pointing to the front 
+    /// element of a linked list in the set.
+    ///
+    /// The cursor points to the "ghost" non-element if the list is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.extend(list_index, vec![1, 2, 3]);
+    /// {
+    ///     let mut cursor = set.cursor_front_mut(list_index);
+    ///     cursor.move_next();
+    ///     cursor.insert_before(10);
+    ///     cursor.insert_after(20);
+    ///     *cursor.current().unwrap() = 30;
+    /// }
+    /// let result: Vec<i32> = set.iter(list_index).copied().collect();
+    ///
+    /// assert_eq!(result, vec![1, 10, 30, 20, 3]);
+    /// ```
+    pub fn cursor_front_mut(&mut self, list_index: ListIndex<Ix>) -> CursorMut<'_, T, Ix, S> {
+        let front = self.get_list_unchecked(list_index).front;
+
+        CursorMut::new(self, list_index, front, 0)
+    }
+
+    /// Provide a cursor with editing operations pointing to the back 
+    /// element of a linked list in the set.
+    ///
+    /// The cursor points to the "ghost" non-element if the list is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.extend(list_index, vec![1, 2, 3, 4]);
+    /// let head_list_index = {
+    ///     let mut cursor = set.cursor_back_mut(list_index);
+    ///     cursor.move_prev();
+    ///     assert_eq!(cursor.remove_current(), Some(3));
+    ///     cursor.split_before()
+    /// };
+    /// let result: Vec<i32> = set.iter(list_index).copied().collect();
+    /// let head_result: Vec<i32> = set.iter(head_list_index).copied().collect();
+    ///
+    /// assert_eq!(result, vec![4]);
+    /// assert_eq!(head_result, vec![1, 2]);
+    /// ```
+    pub fn cursor_back_mut(&mut self, list_index: ListIndex<Ix>) -> CursorMut<'_, T, Ix, S> {
+        let list = self.get_list_unchecked(list_index);
+        let back = list.back;
+        let index = list.len().saturating_sub(1);
+
+        CursorMut::new(self, list_index, back, index)
+    }
+
+    /// Provide an iterator over the handles of the linked lists stored inside
+    /// the set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #    LinkedListSet,    
+    /// # };
+    /// #
+    /// let mut set: LinkedListSet<usize> = LinkedListSet::new();
+    /// set.new_list();
+    /// set.new_list();
+    /// set.new_list();
+    /// set.new_list();
+    /// set.new_list();
+    ///
+    /// assert!(set.list_indices().all(|list_index| {
+    ///     set.contains_list(list_index)  
+    /// }));
+    /// ```
+    pub fn list_indices(&self) -> ListIndices<'_, T, Ix> {
+        ListIndices {
+            owner: self.lists.owner,
+            remaining: self.lists.len(),
+            iter: self.lists.slots.iter().enumerate(),
+        }
+    }
+
+    /// Clear the entire linked list set.
+    ///
+    /// This operation deletes all the nodes and all the linked lists contained in the
+    /// set.
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,  
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.extend(
+    ///     list_index, 
+    ///     vec![String::from("spam"), String::from("eggs"), String::from("pancakes")]
+    /// );
+    ///
+    /// assert!(!set.is_empty());
+    /// set.clear_all();
+    /// assert!(set.is_empty());
+    /// ```
+    pub fn clear_all(&mut self) {
+        self.handles.clear();
+        self.nodes.clear();
+        self.lists.clear();
+    }
+
+    /// Clear the entire linked list set, and reset the storage for linked 
+    /// lists.
+    ///
+    /// Unlike `clear_all`, which keeps track of the generation of every list 
+    /// slot the set has ever handed out, this operation forgets them and
+    /// starts over from an empty list index space. The set takes on a new 
+    /// identity in the process, so list indices handed out before the reset 
+    /// are treated like list indices from a different set.
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,  
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.extend(list_index, vec![1, 2, 3]);
+    ///
+    /// set.clear_all_and_reset();
+    /// let new_list_index = set.new_list();
+    ///
+    /// assert!(!set.contains_list(list_index));
+    /// assert!(set.contains_list(new_list_index));
+    /// ```
+    pub fn clear_all_and_reset(&mut self) {
+        self.handles.clear();
+        self.nodes.clear();
+        self.lists = ListSlots::new();
+    }
+
+    /// Provides an immutable reference to the front element of a list, or
+    /// `None` if the list is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet, 
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    ///
+    /// assert!(set.front(list_index).is_none());
+    ///
+    /// set.extend(
+    ///     list_index, 
+    ///     vec![String::from("spam"), String::from("eggs"), String::from("pancakes")]
+    /// );
+    ///
+    /// assert_eq!(set.front(list_index).map(|p| p.as_str()), Some("spam"));
+    /// ```
+    pub fn front(&self, list_index: ListIndex<Ix>) -> Option<&T> {
+        let front_node_index = {
+            let list = self.get_list(list_index)?;
+            list.front
+        };
+        if front_node_index != NodeIndex::end() {
+            let item = self.get_item_unchecked(front_node_index);
+
+            Some(item)
+        } else {
+            None
+        }
+    }
+
+    /// Provides a mutable reference to the front element of a list, or
+    /// `None` if the list is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet, 
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    ///
+    /// assert!(set.back_mut(list_index).is_none());
+    ///
+    /// set.extend(
+    ///     list_index, 
+    ///     vec![String::from("spam"), String::from("eggs"), String::from("pancakes")]
+    /// );
+    ///
+    /// assert_eq!(set.front_mut(list_index).map(|p| p.as_str()), Some("spam"));
+    /// {
+    ///     let mut front = set.front_mut(list_index).unwrap();
+    ///     *front = String::from("bacon");
+    /// }
+    /// assert_eq!(set.front(list_index).map(|p| p.as_str()), Some("bacon"));
+    /// ```
+    pub fn front_mut(&mut self, list_index: ListIndex<Ix>) -> Option<&mut T> {
+        let front_node_index = {
+            let list = self.get_list(list_index)?;
+            list.front
+        };
+        if front_node_index != NodeIndex::end() {
+            let item = self.get_item_mut_unchecked(front_node_index);
+
+            Some(item)
+        } else {
+            None
+        }
+    }
+
+    /// Provides an immutable reference to the back element of a list, or
+    /// `None` if the list is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet, 
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    ///
+    /// assert!(set.back(list_index).is_none());
+    ///
+    /// set.extend(
+    ///     list_index, 
+    ///     vec![String::from("spam"), String::from("eggs"), String::from("pancakes")]
+    /// );
+    ///
+    /// assert_eq!(set.back(list_index).map(|p| p.as_str()), Some("pancakes"));
+    /// ```
+    pub fn back(&self, list_index: ListIndex<Ix>) -> Option<&T> {
+        let back_node_index = {
+            let list = self.get_list(list_index)?;
+            list.back
+        };
+        if back_node_index != NodeIndex::end() {
+            let item = self.get_item_unchecked(back_node_index);
+
+            Some(item)
+        } else {
+            None
+        }
+    }
+
+    /// Provides a mutable reference to the back element of a list, or
+    /// `None` if the list is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet, 
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    ///
+    /// assert!(set.back_mut(list_index).is_none());
+    ///
+    /// set.extend(
+    ///     list_index, 
+    ///     vec![String::from("spam"), String::from("eggs"), String::from("pancakes")]
+    /// );
+    ///
+    /// assert_eq!(set.back_mut(list_index).map(|p| p.as_str()), Some("pancakes"));
+    /// {
+    ///     let mut back = set.back_mut(list_index).unwrap();
+    ///     *back = String::from("waffles");
+    /// }
+    /// assert_eq!(set.back(list_index).map(|p| p.as_str()), Some("waffles"));
+    /// ```
+    pub fn back_mut(&mut self, list_index: ListIndex<Ix>) -> Option<&mut T> {
+        let back_node_index = {
+            let list = self.get_list(list_index)?;
+            list.back
+        };
+        if back_node_index != NodeIndex::end() {
+            let item = self.get_item_mut_unchecked(back_node_index);
+
+            Some(item)
+        } else {
+            None
+        }
+    }
+
+    /// Determine whether a node handle points to an element stored in the 
+    /// set.
+    ///
+    /// A node handle stops pointing to an element once the element is 
+    /// removed from the set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet, 
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// let handle = set.push_back(list_index, 1);
+    ///
+    /// assert!(set.contains_node(handle));
+    ///
+    /// set.remove_node(handle);
+    ///
+    /// assert!(!set.contains_node(handle));
+    /// ```
+    pub fn contains_node(&self, handle: NodeHandle) -> bool {
+        self.handles.get(handle, self.lists.owner).is_some()
+    }
+
+    /// Provides an immutable reference to the element a node handle points 
+    /// to, or `None` if the handle is no longer valid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet, 
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// let handle = set.push_back(list_index, String::from("spam"));
+    /// set.push_front(list_index, String::from("eggs"));
+    /// set.push_back(list_index, String::from("pancakes"));
+    /// set.pop_front(list_index);
+    ///
+    /// assert_eq!(set.get(handle).map(|p| p.as_str()), Some("spam"));
+    /// ```
+    pub fn get(&self, handle: NodeHandle) -> Option<&T> {
+        let node_index = self.handles.get(handle, self.lists.owner)?;
+        let item = self.get_item_unchecked(node_index);
+
+        Some(item)
+    }
+
+    /// Provides a mutable reference to the element a node handle points 
+    /// to, or `None` if the handle is no longer valid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet, 
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// let handle = set.push_back(list_index, String::from("spam"));
+    /// set.push_front(list_index, String::from("eggs"));
+    /// {
+    ///     let item = set.get_mut(handle).unwrap();
+    ///     *item = String::from("bacon");
+    /// }
+    ///
+    /// assert_eq!(set.back(list_index).map(|p| p.as_str()), Some("bacon"));
+    /// ```
+    pub fn get_mut(&mut self, handle: NodeHandle) -> Option<&mut T> {
+        let node_index = self.handles.get(handle, self.lists.owner)?;
+        let item = self.get_item_mut_unchecked(node_index);
+
+        Some(item)
+    }
+
+    /// Provides an immutable reference to the element at position `at` in a 
+    /// linked list, or `None` if `at` falls outside the length of the list.
+    ///
+    /// The list is traversed from whichever end is closer to `at`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet, 
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.extend(list_index, vec![0, 1, 2, 3, 4, 5]);
+    ///
+    /// assert_eq!(set.get_at(list_index, 1), Some(&1));
+    /// assert_eq!(set.get_at(list_index, 4), Some(&4));
+    /// assert_eq!(set.get_at(list_index, 6), None);
+    /// ```
+    pub fn get_at(&self, list_index: ListIndex<Ix>, at: usize) -> Option<&T> {
+        let node_index = self.find_list_node(list_index, at);
+        if node_index != NodeIndex::end() {
+            Some(self.get_item_unchecked(node_index))
+        } else {
+            None
+        }
+    }
+
+    /// Provides a mutable reference to the element at position `at` in a 
+    /// linked list, or `None` if `at` falls outside the length of the list.
+    ///
+    /// The list is traversed from whichever end is closer to `at`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet, 
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.extend(list_index, vec![0, 1, 2, 3, 4, 5]);
+    /// 
+    /// if let Some(item) = set.get_at_mut(list_index, 4) {
+    ///     *item = 40;
+    /// }
+    ///
+    /// assert_eq!(set.get_at(list_index, 4), Some(&40));
+    /// assert_eq!(set.get_at_mut(list_index, 6), None);
+    /// ```
+    pub fn get_at_mut(&mut self, list_index: ListIndex<Ix>, at: usize) -> Option<&mut T> {
+        let node_index = self.find_list_node(list_index, at);
+        if node_index != NodeIndex::end() {
+            Some(self.get_item_mut_unchecked(node_index))
+        } else {
+            None
+        }
+    }
+
+    /// Link a new node into a linked list in the set.
+    #[inline]
+    fn link_list_node(
+        &mut self, 
+        node_index: NodeIndex<Ix>, 
+        previous_index: NodeIndex<Ix>, 
+        next_index: NodeIndex<Ix>
+    ) {
+        {
+            let node = self.get_node_mut_unchecked(node_index);
+            node.previous = previous_index;
+            node.next = next_index;
+        }
+
+        if next_index != NodeIndex::end() {
+            let next_node = self.get_node_mut_unchecked(next_index);
+            next_node.previous = node_index;
+        }
+
+        if previous_index != NodeIndex::end() {
+            let previous_node = self.get_node_mut_unchecked(previous_index);
+            previous_node.next = node_index;
+        }
+    }
+
+    /// Push an item to the back of a linked list in a linked list set.
+    ///
+    /// Returns a handle to the new element that remains valid until the 
+    /// element is removed from the set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,  
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    ///
+    /// assert!(set.back(list_index).is_none());
+    ///
+    /// set.extend(list_index, vec![0, 1, 2, 3, 4, 5]);
+    /// 
+    /// assert_eq!(set.back(list_index), Some(&5));
+    //
+    /// set.push_back(list_index, 300);
+    ///
+    /// assert_eq!(set.back(list_index), Some(&300));
+    /// ```
+    pub fn push_back(&mut self, list_index: ListIndex<Ix>, item: T) -> NodeHandle {
+        let (new_node_index, new_handle) = self.new_list_node(list_index, item);
+        if self.get_list_unchecked(list_index).is_empty() {
+            let list = self.get_list_mut_unchecked(list_index);
+            list.front = new_node_index;
+            list.back = new_node_index;
+        } else {
+            let back = self.get_list_unchecked(list_index).back;
+            self.link_list_node(
+                new_node_index, 
+                back, 
+                NodeIndex::end()
+            );
+            self.get_list_mut_unchecked(list_index).back = new_node_index;
+        }
+
+        self.get_list_mut_unchecked(list_index).length += 1;
+
+        new_handle
+    }
+
+    /// Push an item to the back of a linked list in a linked list set, or 
+    /// return an error if the list index does not point to a linked list in 
+    /// the set.
+    ///
+    /// Unlike `push_back`, this function also returns an error instead of aborting 
+    /// if the set fails to allocate storage for the item.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,  
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// 
+    /// assert!(set.try_push_back(list_index, 1).is_ok());
+    /// assert_eq!(set.back(list_index), Some(&1));
+    ///
+    /// set.remove_list(list_index);
+    ///
+    /// assert!(set.try_push_back(list_index, 2).is_err());
+    /// ```
+    pub fn try_push_back(&mut self, list_index: ListIndex<Ix>, item: T) -> Result<NodeHandle, ListSetError<Ix>> {
+        self.try_get_list(list_index)?;
+        self.try_reserve(1)?;
+
+        Ok(self.push_back(list_index, item))
+    }
+
+    /// Push an item to the front of a linked list in a linked list set.
+    ///
+    /// Returns a handle to the new element that remains valid until the 
+    /// element is removed from the set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,  
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    ///
+    /// assert!(set.front(list_index).is_none());
+    ///
+    /// set.extend(list_index, vec![0, 1, 2, 3, 4, 5]);
+    /// 
+    /// assert_eq!(set.front(list_index), Some(&0));
+    //
+    /// set.push_front(list_index, 300);
+    ///
+    /// assert_eq!(set.front(list_index), Some(&300));
+    /// ```
+    pub fn push_front(&mut self, list_index: ListIndex<Ix>, item: T) -> NodeHandle {
+        let (new_node_index, new_handle) = self.new_list_node(list_index, item);
+        if self.get_list_unchecked(list_index).is_empty() {
+            let list = self.get_list_mut_unchecked(list_index);
+            list.front = new_node_index;
+            list.back = new_node_index;
+        } else {
+            let front = self.get_list_unchecked(list_index).front;
+            self.link_list_node(
+                new_node_index, 
+                NodeIndex::end(),
+                front
+            );
+            self.get_list_mut_unchecked(list_index).front = new_node_index;
+        }
+
+        self.get_list_mut_unchecked(list_index).length += 1;
+
+        new_handle
+    }
+
+    /// Push an item to the front of a linked list in a linked list set, or 
+    /// return an error if the list index does not point to a linked list in 
+    /// the set.
+    ///
+    /// Unlike `push_front`, this function also returns an error instead of aborting 
+    /// if the set fails to allocate storage for the item.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,  
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// 
+    /// assert!(set.try_push_front(list_index, 1).is_ok());
+    /// assert_eq!(set.front(list_index), Some(&1));
+    ///
+    /// set.remove_list(list_index);
+    ///
+    /// assert!(set.try_push_front(list_index, 2).is_err());
+    /// ```
+    pub fn try_push_front(&mut self, list_index: ListIndex<Ix>, item: T) -> Result<NodeHandle, ListSetError<Ix>> {
+        self.try_get_list(list_index)?;
+        self.try_reserve(1)?;
+
+        Ok(self.push_front(list_index, item))
+    }
+
+    /// Insert an item at position `at` in a linked list, shifting all the 
+    /// items after it towards the back of the list.
+    ///
+    /// Returns a handle to the new element, or hands the item back as an 
+    /// error if `at` is greater than the length of the list, in which case 
+    /// the list is unchanged. The list is traversed from whichever end is 
+    /// closer to `at`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,  
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.extend(list_index, vec![0, 1, 3, 4]);
+    ///
+    /// assert!(set.insert(list_index, 2, 2).is_ok());
+    /// assert!(set.insert(list_index, 5, 5).is_ok());
+    /// assert_eq!(set.insert(list_index, 7, 7), Err(7));
+    ///
+    /// let result: Vec<i32> = set.iter(list_index).copied().collect();
+    ///
+    /// assert_eq!(result, vec![0, 1, 2, 3, 4, 5]);
+    /// ```
+    pub fn insert(&mut self, list_index: ListIndex<Ix>, at: usize, item: T) -> Result<NodeHandle, T> {
+        let length = self.len(list_index);
+        if at > length {
+            return Err(item);
+        }
+
+        let (previous_index, next_index) = if at == length {
+            (self.get_list_unchecked(list_index).back, NodeIndex::end())
+        } else {
+            let next_index = self.find_list_node(list_index, at);
+            (self.get_node_unchecked(next_index).previous(), next_index)
+        };
+
+        Ok(self.insert_list_node(list_index, previous_index, next_index, item))
+    }
+
+    /// Unlink a node from a linked list.
+    fn unlink_list_node(&mut self, node_index: NodeIndex<Ix>) {
+        let previous_index = {
+            let node = self.get_node_unchecked(node_index);
+            node.previous
+        };
+        let next_index = {
+            let node = self.get_node_unchecked(node_index);
+            node.next
+        };
+
+        if previous_index != NodeIndex::end() {
+            let previous_node = self.get_node_mut_unchecked(previous_index);
+            previous_node.next = next_index;
+        }
+        
+        if next_index != NodeIndex::end() {
+            let next_node = self.get_node_mut_unchecked(next_index);
+            next_node.previous = previous_index;
+        }
+
+        let list_index = self.get_node_list_unchecked(node_index);
+        let list = self.get_list_mut_unchecked(list_index);
+
+        if list.front == node_index {
+            list.front = next_index;
+        }
+
+        if list.back == node_index {
+            list.back = previous_index;
+        }
+
+        list.length -= 1;
+    }
+
+    /// Relink a list node after moving it to a different entry in the 
+    /// underlying storage.
+    fn relink_list_node(
+        &mut self, 
+        old_node_index: NodeIndex<Ix>, 
+        new_node_index: NodeIndex<Ix>
+    ) {
+        if old_node_index != new_node_index {
+            let previous_index = {
+                let node = self.get_node_unchecked(old_node_index);
+                node.previous
+            };
+            let next_index = {
+                let node = self.get_node_unchecked(old_node_index);
+                node.next
+            };
+        
+            if previous_index != NodeIndex::end() {
+                let previous_node = self.get_node_mut_unchecked(previous_index);
+                previous_node.next = new_node_index;
+            }
+
+            if next_index != NodeIndex::end() {
+                let next_node = self.get_node_mut_unchecked(next_index);
+                next_node.previous = new_node_index;
+            }
+
+            // Check front and back of the list of the node index being 
+            // moved, old_node_index.
+            let list_index = self.get_node_list_unchecked(old_node_index);
+            let list = self.get_list_mut_unchecked(list_index);
+        
+            if list.front == old_node_index {
+                list.front = new_node_index;
+            }
+        
+            if list.back == old_node_index {
+                list.back = new_node_index;
+            } 
+        }
+    }
+
+    /// Exchange the places of two nodes in the same linked list by relinking 
+    /// them.
+    fn swap_list_nodes(
+        &mut self, 
+        list_index: ListIndex<Ix>, 
+        node_index_a: NodeIndex<Ix>, 
+        node_index_b: NodeIndex<Ix>
+    ) {
+        if node_index_a == node_index_b {
+            return;
+        }
+
+        let (previous_a, next_a) = {
+            let node = self.get_node_unchecked(node_index_a);
+            (node.previous(), node.next())
+        };
+        let (previous_b, next_b) = {
+            let node = self.get_node_unchecked(node_index_b);
+            (node.previous(), node.next())
+        };
+
+        if next_a == node_index_b {
+            self.link_list_node(node_index_b, previous_a, node_index_a);
+            self.link_list_node(node_index_a, node_index_b, next_b);
+        } else if next_b == node_index_a {
+            self.link_list_node(node_index_a, previous_b, node_index_b);
+            self.link_list_node(node_index_b, node_index_a, next_a);
+        } else {
+            self.link_list_node(node_index_a, previous_b, next_b);
+            self.link_list_node(node_index_b, previous_a, next_a);
+        }
+
+        let list = self.get_list_mut_unchecked(list_index);
+        if list.front == node_index_a {
+            list.front = node_index_b;
+        } else if list.front == node_index_b {
+            list.front = node_index_a;
+        }
+
+        if list.back == node_index_a {
+            list.back = node_index_b;
+        } else if list.back == node_index_b {
+            list.back = node_index_a;
+        }
+    }
+
+    /// Exchange the positions of two nodes in the underlying storage, 
+    /// rewriting the links, list ends and handles that refer to them.
+    ///
+    /// The two nodes may belong to the same list, and may be adjacent.
+    fn swap_node_storage(&mut self, a: NodeIndex<Ix>, b: NodeIndex<Ix>) {
+        if a == b {
+            return;
+        }
+
+        let relocated = |node_index: NodeIndex<Ix>| {
+            if node_index == a {
+                b
+            } else if node_index == b {
+                a
+            } else {
+                node_index
+            }
+        };
+        self.nodes.swap(a.index(), b.index());
+
+        for node_index in [a, b] {
+            let node = self.get_node_mut_unchecked(node_index);
+            node.previous = relocated(node.previous);
+            node.next = relocated(node.next);
+        }
+
+        for node_index in [a, b] {
+            let (previous_index, next_index, handle) = {
+                let node = self.get_node_unchecked(node_index);
+                (node.previous(), node.next(), node.handle())
+            };
+            if previous_index != NodeIndex::end() {
+                self.get_node_mut_unchecked(previous_index).next = node_index;
+            }
+
+            if next_index != NodeIndex::end() {
+                self.get_node_mut_unchecked(next_index).previous = node_index;
+            }
+
+            self.handles.relocate(handle, node_index);
+        }
+
+        let list_index_a = self.get_node_list_unchecked(a);
+        let list_index_b = self.get_node_list_unchecked(b);
+        for list_index in [list_index_a, list_index_b] {
+            let list = self.get_list_mut_unchecked(list_index);
+            list.front = relocated(list.front);
+            list.back = relocated(list.back);
+            if list_index_a == list_index_b {
+                break;
+            }
+        }
+    }
+
+    /// Place the next node of the current defragmentation pass in its final
+    /// position in the underlying storage.
+    ///
+    /// Returns `false` if the pass is finished.
+    fn defragment_step(&mut self) -> bool {
+        let state = self.defragment;
+        if state.position >= self.nodes.len() {
+            return false;
+        }
+
+        let node_index = match state.next.and_then(|handle| self.handles.get(handle, self.lists.owner)) {
+            Some(node_index) => node_index,
+            None => {
+                let mut list_slot = state.list_slot;
+                let front = loop {
+                    let slot = match self.lists.slots.get(list_slot) {
+                        Some(slot) => slot,
+                        None => return false,
+                    };
+                    list_slot += 1;
+                    match slot.list.as_ref() {
+                        Some(list) if list.front != NodeIndex::end() => break list.front,
+                        _ => {}
+                    }
+                };
+                self.defragment.list_slot = list_slot;
+
+                front
+            }
+        };
+
+        // A node can only come before the current position if the set was 
+        // modified in the middle of the pass. It stays where it is.
+        let placed_index = if node_index.index() >= state.position {
+            let target_index = NodeIndex::new(state.position);
+            self.swap_node_storage(node_index, target_index);
+            self.defragment.position += 1;
+
+            target_index
+        } else {
+            node_index
+        };
+
+        let next_index = self.get_node_unchecked(placed_index).next();
+        self.defragment.next = if next_index != NodeIndex::end() {
+            Some(self.node_handle(next_index))
+        } else {
+            None
+        };
+
+        true
+    }
+
+    /// Remove a linked list node from the set.
+    ///
+    /// The function unlinks the node with the input node index from its linked 
+    /// list. It may internally modify the layout of other linked lists in the 
+    /// process of removing the node to keep the nodes in the underlying storage 
+    /// packed.
+    ///
+    /// # Assumptions
+    /// * The list node index exists in the set.
+    /// 
+    /// # Panics
+    ///
+    /// This function panics if `node_to_be_removed_index` is out of bounds.
+    fn remove_list_node(&mut self, node_to_be_removed_index: NodeIndex<Ix>) -> T {
+        let node_to_be_moved_index = NodeIndex::new(self.nodes.len() - 1);
+        self.unlink_list_node(node_to_be_removed_index);
+        self.relink_list_node(node_to_be_moved_index, node_to_be_removed_index);
+
+        let (removed, item) = self.nodes.swap_remove(node_to_be_removed_index.index());
+        self.handles.free_handle(removed.handle());
+        if node_to_be_moved_index != node_to_be_removed_index {
+            let moved_handle = self.get_node_unchecked(node_to_be_removed_index).handle();
+            self.handles.relocate(moved_handle, node_to_be_removed_index);
+        }
+
+        item
+    }
+
+    /// Remove a linked list node from the set, keeping track of another node 
+    /// across the removal.
+    ///
+    /// Removing a node relocates the last node in the underlying storage into 
+    /// the position of the removed node. If `tracked_index` is the position of
+    /// the relocated node, it gets updated to the new position of the node.
+    fn remove_list_node_tracking(
+        &mut self, 
+        node_to_be_removed_index: NodeIndex<Ix>, 
+        tracked_index: &mut NodeIndex<Ix>
+    ) -> T {
+        let node_to_be_moved_index = NodeIndex::new(self.nodes.len() - 1);
+        let item = self.remove_list_node(node_to_be_removed_index);
+        if *tracked_index == node_to_be_moved_index {
+            *tracked_index = node_to_be_removed_index;
+        }
+
+        item
+    }
+
+    /// Allocate an unlinked node for an item of a linked list at the end of 
+    /// the underlying storage.
+    ///
+    /// The list index is checked before anything is allocated, so a failed 
+    /// push leaves the set unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `list_index` does not point to a linked list in the set, or 
+    /// if the set already holds as many nodes as its index type can 
+    /// represent.
+    fn new_list_node(&mut self, list_index: ListIndex<Ix>, item: T) -> (NodeIndex<Ix>, NodeHandle) {
+        self.get_list_unchecked(list_index);
+        if let Err(error) = self.check_node_capacity(1) {
+            panic!("{}", error);
+        }
+
+        let new_node_index = NodeIndex::new(self.nodes.len());
+        let new_handle = self.handles.new_handle(new_node_index, self.lists.owner);
+        let new_node = Node::new(new_handle.slot);
+        self.nodes.push(new_node, list_index.index, item);
+
+        (new_node_index, new_handle)
+    }
+
+    /// Allocate a node for an item, and link it into a linked list between 
+    /// the adjacent nodes `previous_index` and `next_index`.
+    ///
+    /// Either index can be `NodeIndex::end()`, in which case the new node 
+    /// becomes the front or the back of the list respectively.
+    fn insert_list_node(
+        &mut self, 
+        list_index: ListIndex<Ix>, 
+        previous_index: NodeIndex<Ix>, 
+        next_index: NodeIndex<Ix>, 
+        item: T
+    ) -> NodeHandle {
+        let (new_node_index, new_handle) = self.new_list_node(list_index, item);
+        self.link_list_node(new_node_index, previous_index, next_index);
+
+        let list = self.get_list_mut_unchecked(list_index);
+        if previous_index == NodeIndex::end() {
+            list.front = new_node_index;
+        }
+
+        if next_index == NodeIndex::end() {
+            list.back = new_node_index;
+        }
+
+        list.length += 1;
+
+        new_handle
+    }
+
+    /// Move every node of the linked list `source_index` into the linked list
+    /// `target_index` between the adjacent nodes `previous_index` and 
+    /// `next_index`, leaving the source list empty.
+    ///
+    /// The nodes stay where they are in the underlying storage. Only their 
+    /// links and the list they belong to change.
+    fn splice_list_nodes(
+        &mut self, 
+        source_index: ListIndex<Ix>, 
+        target_index: ListIndex<Ix>, 
+        previous_index: NodeIndex<Ix>, 
+        next_index: NodeIndex<Ix>
+    ) {
+        let source = self.get_list_unchecked(source_index).clone();
+        if source.is_empty() {
+            return;
+        }
+
+        let mut current_index = source.front;
+        while current_index != NodeIndex::end() {
+            *self.get_node_list_mut_unchecked(current_index) = target_index.index;
+            current_index = self.get_node_unchecked(current_index).next();
+        }
+
+        self.get_node_mut_unchecked(source.front).previous = previous_index;
+        self.get_node_mut_unchecked(source.back).next = next_index;
+
+        if previous_index != NodeIndex::end() {
+            self.get_node_mut_unchecked(previous_index).next = source.front;
+        }
+
+        if next_index != NodeIndex::end() {
+            self.get_node_mut_unchecked(next_index).previous = source.back;
+        }
+
+        let target = self.get_list_mut_unchecked(target_index);
+        if previous_index == NodeIndex::end() {
+            target.front = source.front;
+        }
+
+        if next_index == NodeIndex::end() {
+            target.back = source.back;
+        }
+
+        target.length += source.length;
+
+        *self.get_list_mut_unchecked(source_index) = LinkedList::new();
+    }
+
+    /// Move the run of nodes from `first_index` to `last_index` inclusive out 
+    /// of a linked list, and into a new linked list in the set.
+    ///
+    /// The node `last_index` must come at or after the node `first_index` in
+    /// the list. If `first_index` is `NodeIndex::end()`, the new list is 
+    /// empty.
+    fn split_list_nodes(
+        &mut self, 
+        list_index: ListIndex<Ix>, 
+        first_index: NodeIndex<Ix>, 
+        last_index: NodeIndex<Ix>
+    ) -> ListIndex<Ix> {
+        let new_list_index = self.new_list();
+        if first_index == NodeIndex::end() {
+            return new_list_index;
+        }
+
+        let previous_index = self.get_node_unchecked(first_index).previous();
+        let next_index = self.get_node_unchecked(last_index).next();
+        let mut length = 0;
+        let mut current_index = first_index;
+        while current_index != next_index {
+            *self.get_node_list_mut_unchecked(current_index) = new_list_index.index;
+            current_index = self.get_node_unchecked(current_index).next();
+            length += 1;
+        }
+
+        if previous_index != NodeIndex::end() {
+            self.get_node_mut_unchecked(previous_index).next = next_index;
+        }
+
+        if next_index != NodeIndex::end() {
+            self.get_node_mut_unchecked(next_index).previous = previous_index;
+        }
+
+        let list = self.get_list_mut_unchecked(list_index);
+        if list.front == first_index {
+            list.front = next_index;
+        }
+
+        if list.back == last_index {
+            list.back = previous_index;
+        }
+
+        list.length -= length;
+
+        self.get_node_mut_unchecked(first_index).previous = NodeIndex::end();
+        self.get_node_mut_unchecked(last_index).next = NodeIndex::end();
+
+        let new_list = self.get_list_mut_unchecked(new_list_index);
+        new_list.front = first_index;
+        new_list.back = last_index;
+        new_list.length = length;
+
+        new_list_index
+    }
+
+    /// Move a node to the front or back of a linked list in the set without 
+    /// moving it in the underlying storage.
+    ///
+    /// The target list may be the list the node already belongs to.
+    fn move_list_node(&mut self, node_index: NodeIndex<Ix>, target_index: ListIndex<Ix>, to_front: bool) {
+        self.get_list_unchecked(target_index);
+        self.unlink_list_node(node_index);
+        *self.get_node_list_mut_unchecked(node_index) = target_index.index;
+
+        let (previous_index, next_index) = {
+            let target = self.get_list_unchecked(target_index);
+            if to_front {
+                (NodeIndex::end(), target.front)
+            } else {
+                (target.back, NodeIndex::end())
+            }
+        };
+        self.link_list_node(node_index, previous_index, next_index);
+
+        let target = self.get_list_mut_unchecked(target_index);
+        if previous_index == NodeIndex::end() {
+            target.front = node_index;
+        }
+
+        if next_index == NodeIndex::end() {
+            target.back = node_index;
+        }
+
+        target.length += 1;
+    }
+
+    /// Sort the nodes of a linked list with a stable bottom-up merge sort 
+    /// over the node links.
+    ///
+    /// The sort only rewires the `next` links while merging runs of doubling
+    /// length, then rebuilds the `previous` links in one final pass. No nodes
+    /// move in the underlying storage. If `compare` panics, the nodes are 
+    /// linked back into one valid list in whatever order the sort had
+    /// reached.
+    fn sort_list_nodes<F>(&mut self, list_index: ListIndex<Ix>, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering
+    {
+        let front = {
+            let list = self.get_list_unchecked(list_index);
+            if list.len() < 2 {
+                return;
+            }
+
+            list.front
+        };
+        let mut sort = ListSort {
+            set: self,
+            list_index: list_index,
+            head: NodeIndex::end(),
+            tail: NodeIndex::end(),
+            left: front,
+            left_length: 0,
+            right: front,
+        };
+        let mut run_length = 1;
+        loop {
+            let mut merges = 0;
+            while sort.left != NodeIndex::end() {
+                merges += 1;
+                while sort.left_length < run_length && sort.right != NodeIndex::end() {
+                    sort.left_length += 1;
+                    sort.right = sort.set.get_node_unchecked(sort.right).next();
+                }
+
+                let mut right_length = run_length;
+                while sort.left_length > 0 || (right_length > 0 && sort.right != NodeIndex::end()) {
+                    let take_left = if sort.left_length == 0 {
+                        false
+                    } else if right_length == 0 || sort.right == NodeIndex::end() {
+                        true
+                    } else {
+                        let left_item = sort.set.get_item_unchecked(sort.left);
+                        let right_item = sort.set.get_item_unchecked(sort.right);
+                        compare(left_item, right_item) != Ordering::Greater
+                    };
+                    let node_index = if take_left {
+                        let node_index = sort.left;
+                        sort.left = sort.set.get_node_unchecked(sort.left).next();
+                        sort.left_length -= 1;
+                        node_index
+                    } else {
+                        let node_index = sort.right;
+                        sort.right = sort.set.get_node_unchecked(sort.right).next();
+                        right_length -= 1;
+                        node_index
+                    };
+
+                    sort.push(node_index);
+                }
+
+                sort.left = sort.right;
+            }
+
+            if merges <= 1 {
+                break;
+            }
+
+            sort.set.get_node_mut_unchecked(sort.tail).next = NodeIndex::end();
+            sort.left = sort.head;
+            sort.right = sort.head;
+            sort.head = NodeIndex::end();
+            sort.tail = NodeIndex::end();
+            run_length *= 2;
+        }
+    }
+
+    /// Remove and return an item at a specific position in a linked list.
+    ///
+    /// Returns `None` if the list is empty, or `at` falls outside the length 
+    /// of the list. The list is traversed from whichever end is closer to 
+    /// `at`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.extend(list_index, vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    ///
+    /// assert!(set.remove(list_index, 5).is_some());
+    /// assert!(set.remove(list_index, 11).is_none());
+    /// ```
+    pub fn remove(&mut self, list_index: ListIndex<Ix>, at: usize) -> Option<(T, usize)> {
+        let node_index = self.find_list_node(list_index, at);
+        if node_index != NodeIndex::end() {
+            let res = self.remove_list_node(node_index);
+
+            Some((res, at))
+        } else {
+            None
+        }
+    }
+
+    /// Remove and return an item at a specific position in a linked list.
+    ///
+    /// Returns an error if the list index does not point to a linked list in 
+    /// the set, or `at` falls outside the length of the list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// #     ListSetError,
     /// # };
     /// #
-    /// let mut set: LinkedListSet<usize> = LinkedListSet::new();
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.extend(list_index, vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    ///
+    /// assert_eq!(set.try_remove(list_index, 5), Ok(5));
+    /// assert_eq!(
+    ///     set.try_remove(list_index, 11), 
+    ///     Err(ListSetError::IndexOutOfBounds { index: 11, length: 9 })
+    /// );
+    /// ```
+    pub fn try_remove(&mut self, list_index: ListIndex<Ix>, at: usize) -> Result<T, ListSetError<Ix>> {
+        let length = self.try_len(list_index)?;
+        if at >= length {
+            return Err(ListSetError::IndexOutOfBounds { index: at, length: length });
+        }
+
+        match self.remove(list_index, at) {
+            Some((item, _)) => Ok(item),
+            None => Err(ListSetError::IndexOutOfBounds { index: at, length: length }),
+        }
+    }
+
+    /// Replace the item at position `at` in a linked list, and return the old
+    /// item.
+    ///
+    /// Hands the new item back as an error if `at` falls outside the length 
+    /// of the list, in which case the list is unchanged. The list is 
+    /// traversed from whichever end is closer to `at`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.extend(list_index, vec![0, 1, 2, 3]);
+    ///
+    /// assert_eq!(set.replace(list_index, 2, 20), Ok(2));
+    /// assert_eq!(set.replace(list_index, 4, 40), Err(40));
+    /// 
+    /// let result: Vec<i32> = set.iter(list_index).copied().collect();
+    ///
+    /// assert_eq!(result, vec![0, 1, 20, 3]);
+    /// ```
+    pub fn replace(&mut self, list_index: ListIndex<Ix>, at: usize, item: T) -> Result<T, T> {
+        match self.get_at_mut(list_index, at) {
+            Some(current) => Ok(std::mem::replace(current, item)),
+            None => Err(item),
+        }
+    }
+
+    /// Swap the elements at positions `i` and `j` in a linked list.
+    ///
+    /// The elements trade places by relinking their nodes, so the items stay
+    /// where they are in the underlying storage, and node handles to either 
+    /// element continue to point to the same element. Returns an error if 
+    /// `list_index` does not point to a linked list in the set, or if either 
+    /// position falls outside the length of the list, in which case the list 
+    /// is unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// #     ListSetError,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.extend(list_index, vec![0, 1, 2, 3, 4]);
+    ///
+    /// assert!(set.swap(list_index, 1, 3).is_ok());
+    /// assert_eq!(
+    ///     set.swap(list_index, 1, 5), 
+    ///     Err(ListSetError::IndexOutOfBounds { index: 5, length: 5 })
+    /// );
+    /// 
+    /// let result: Vec<i32> = set.iter(list_index).copied().collect();
+    ///
+    /// assert_eq!(result, vec![0, 3, 2, 1, 4]);
+    /// ```
+    pub fn swap(&mut self, list_index: ListIndex<Ix>, i: usize, j: usize) -> Result<(), ListSetError<Ix>> {
+        let length = self.try_len(list_index)?;
+        for at in [i, j] {
+            if at >= length {
+                return Err(ListSetError::IndexOutOfBounds { index: at, length: length });
+            }
+        }
+
+        let node_index_i = self.find_list_node(list_index, i);
+        let node_index_j = self.find_list_node(list_index, j);
+        self.swap_list_nodes(list_index, node_index_i, node_index_j);
+
+        Ok(())
+    }
+
+    /// Remove and return the first instance of an item from a linked list
+    /// in a linked list set.
+    ///
+    /// Returns `None` if the linked list is empty, or `item` is not present 
+    /// in the list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet, 
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index0 = set.new_list();
+    /// // Insert a collection with exactly one instance of `1`.
+    /// set.extend(list_index0, vec![0, 0, 0, 1, 0, 0, 0]);
+    /// 
+    /// assert!(set.contains(list_index0, &1));
+    /// assert!(set.remove_item(list_index0, &1).is_some());
+    /// // The set no longer contains the removed item.
+    /// assert!(!set.contains(list_index0, &1));
+    ///
+    /// let list_index1 = set.new_list();
+    /// // Insert a collection with more than one instance of `1`.
+    /// set.extend(list_index1, vec![0, 0, 0, 1, 0, 1, 0]);
+    ///
+    /// assert!(set.contains(list_index1, &1));
+    /// assert!(set.remove_item(list_index1, &1).is_some());
+    /// // The set still contains an instance of the removed item.
+    /// assert!(set.contains(list_index1, &1));
+    /// ```
+    pub fn remove_item(&mut self, list_index: ListIndex<Ix>, item: &T) -> Option<(T, usize)> 
+    where
+        T: PartialEq<T>
+    {
+        let mut current_index = self.get_list_unchecked(list_index).front;
+        let mut i = 0;
+        while current_index != NodeIndex::end() {
+            if self.get_item_unchecked(current_index) == item {
+                let res = self.remove_list_node(current_index);
+
+                return Some((res, i));
+            } else {
+                current_index = self.get_node_unchecked(current_index).next();
+                i += 1;
+            }
+        }
+
+        None
+    }
+
+    /// Remove and return the first instance of an item from a linked list
+    /// in a linked list set.
+    ///
+    /// Returns `Ok(None)` if `item` is not present in the list, and an error 
+    /// if the list index does not point to a linked list in the set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet, 
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.extend(list_index, vec![0, 0, 0, 1, 0, 0, 0]);
+    /// 
+    /// assert_eq!(set.try_remove_item(list_index, &1), Ok(Some((1, 3))));
+    /// assert_eq!(set.try_remove_item(list_index, &1), Ok(None));
+    ///
+    /// set.remove_list(list_index);
+    ///
+    /// assert!(set.try_remove_item(list_index, &0).is_err());
+    /// ```
+    pub fn try_remove_item(&mut self, list_index: ListIndex<Ix>, item: &T) -> Result<Option<(T, usize)>, ListSetError<Ix>>
+    where
+        T: PartialEq<T>
+    {
+        self.try_get_list(list_index)?;
+
+        Ok(self.remove_item(list_index, item))
+    }
+
+    /// Retain only the elements of a linked list that satisfy a predicate.
+    ///
+    /// The list is traversed once from front to back, and the elements for 
+    /// which `f` returns `false` are removed. The remaining elements keep 
+    /// their order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.extend(list_index, vec![1, 2, 3, 4, 5, 6]);
+    /// set.retain(list_index, |item| item % 2 == 0);
+    /// let result: Vec<usize> = set.iter(list_index).copied().collect();
+    ///
+    /// assert_eq!(result, vec![2, 4, 6]);
+    /// ```
+    pub fn retain<F>(&mut self, list_index: ListIndex<Ix>, mut f: F)
+    where
+        F: FnMut(&T) -> bool
+    {
+        self.retain_mut(list_index, |item| f(item));
+    }
+
+    /// Retain only the elements of a linked list that satisfy a predicate,
+    /// passing a mutable reference to each element to the predicate.
+    ///
+    /// The list is traversed once from front to back, and the elements for 
+    /// which `f` returns `false` are removed. The remaining elements keep 
+    /// their order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.extend(list_index, vec![1, 2, 3, 4, 5, 6]);
+    /// set.retain_mut(list_index, |item| {
+    ///     *item *= 10;
+    ///     *item > 30
+    /// });
+    /// let result: Vec<usize> = set.iter(list_index).copied().collect();
+    ///
+    /// assert_eq!(result, vec![40, 50, 60]);
+    /// ```
+    pub fn retain_mut<F>(&mut self, list_index: ListIndex<Ix>, mut f: F)
+    where
+        F: FnMut(&mut T) -> bool
+    {
+        for _ in self.extract_if(list_index, |item| !f(item)) {}
+    }
+
+    /// Create an iterator that removes and yields the elements of a linked 
+    /// list that satisfy a predicate.
+    ///
+    /// The iterator is lazy: each call to `next` advances through the list 
+    /// until it finds an element for which `f` returns `true`, then removes 
+    /// and returns it. If the iterator is dropped before it is exhausted, the 
+    /// remaining elements stay in the list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `list_index` does not point to a linked list in the set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.extend(list_index, vec![1, 2, 3, 4, 5, 6]);
+    /// let extracted: Vec<usize> = set.extract_if(list_index, |item| *item % 3 == 0).collect();
+    /// let result: Vec<usize> = set.iter(list_index).copied().collect();
+    ///
+    /// assert_eq!(extracted, vec![3, 6]);
+    /// assert_eq!(result, vec![1, 2, 4, 5]);
+    /// ```
+    pub fn extract_if<F>(&mut self, list_index: ListIndex<Ix>, f: F) -> ExtractIf<'_, T, F, Ix, S>
+    where
+        F: FnMut(&mut T) -> bool
+    {
+        let front = self.get_list_unchecked(list_index).front;
+
+        ExtractIf {
+            current: front,
+            predicate: f,
+            set: self,
+        }
+    }
+
+    /// Remove and return the element a node handle points to.
+    ///
+    /// Returns `None` if the handle is no longer valid. Removing an element 
+    /// through its handle takes constant time.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet, 
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.push_back(list_index, 1);
+    /// let handle = set.push_back(list_index, 2);
+    /// set.push_back(list_index, 3);
+    ///
+    /// assert_eq!(set.remove_node(handle), Some(2));
+    /// assert_eq!(set.remove_node(handle), None);
+    ///
+    /// let result: Vec<i32> = set.iter(list_index).copied().collect();
+    ///
+    /// assert_eq!(result, vec![1, 3]);
+    /// ```
+    pub fn remove_node(&mut self, handle: NodeHandle) -> Option<T> {
+        let node_index = self.handles.get(handle, self.lists.owner)?;
+        let item = self.remove_list_node(node_index);
+
+        Some(item)
+    }
+
+    /// Remove and return the first element from a linked list in a linked
+    /// list set.
+    ///
+    /// Returns `None` if the linked list is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet, 
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.push_back(list_index, 1);
+    /// set.push_back(list_index, 2);
+    /// set.push_back(list_index, 3);
+    ///
+    /// let result = set.pop_front(list_index);
+    /// assert_eq!(result, Some(1));
+    /// ```
+    pub fn pop_front(&mut self, list_index: ListIndex<Ix>) -> Option<T> {
+        let front_node_index = self.get_list_unchecked(list_index).front;
+        if front_node_index != NodeIndex::end() {
+            let item = self.remove_list_node(front_node_index);
+
+            return Some(item);
+        }
+
+        None
+    }
+
+    /// Remove and return the first element from a linked list in a linked
+    /// list set.
+    ///
+    /// Returns `Ok(None)` if the linked list is empty, and an error if the 
+    /// list index does not point to a linked list in the set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet, 
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.push_back(list_index, 1);
+    ///
+    /// assert_eq!(set.try_pop_front(list_index), Ok(Some(1)));
+    /// assert_eq!(set.try_pop_front(list_index), Ok(None));
+    ///
+    /// set.remove_list(list_index);
     ///
-    /// assert!(set.is_empty());
+    /// assert!(set.try_pop_front(list_index).is_err());
     /// ```
-    pub fn new() -> Self {
-        Self {
-            alloc: ListIndexAllocator::new(),
-            lists: FnvHashMap::default(),
-            nodes: Vec::new(),
-        }
+    pub fn try_pop_front(&mut self, list_index: ListIndex<Ix>) -> Result<Option<T>, ListSetError<Ix>> {
+        self.try_get_list(list_index)?;
+
+        Ok(self.pop_front(list_index))
     }
 
-    /// Create a new linked list set with the specified capacity of
-    /// linked lists and linked list nodes.
+    /// Remove and return the last element from a linked list in a linked
+    /// list set.
+    ///
+    /// Returns `None` if the linked list is empty.
     ///
     /// # Example
     ///
@@ -449,22 +4841,31 @@ impl<T> LinkedListSet<T> {
     /// #     LinkedListSet, 
     /// # };
     /// #
-    /// let node_capacity = 3000; 
-    /// let mut set: LinkedListSet<usize> = LinkedListSet::with_capacity(
-    ///     node_capacity
-    /// );
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.push_back(list_index, 1);
+    /// set.push_back(list_index, 2);
+    /// set.push_back(list_index, 3);
     ///
-    /// assert_eq!(set.node_capacity(), node_capacity);
+    /// let result = set.pop_back(list_index);
+    /// assert_eq!(result, Some(3));
     /// ```
-    pub fn with_capacity(node_capacity: usize) -> Self {
-        Self {
-            alloc: ListIndexAllocator::new(),
-            lists: FnvHashMap::default(),
-            nodes: Vec::with_capacity(node_capacity),
+    pub fn pop_back(&mut self, list_index: ListIndex<Ix>) -> Option<T> {
+        let back_node_index = self.get_list_unchecked(list_index).back;
+        if back_node_index != NodeIndex::end() {
+            let item = self.remove_list_node(back_node_index);
+
+            return Some(item);
         }
+
+        None
     }
 
-    /// Create a new empty linked list in a linked list set.
+    /// Remove and return the last element from a linked list in a linked
+    /// list set.
+    ///
+    /// Returns `Ok(None)` if the linked list is empty, and an error if the 
+    /// list index does not point to a linked list in the set.
     ///
     /// # Example
     ///
@@ -473,849 +4874,1050 @@ impl<T> LinkedListSet<T> {
     /// #     LinkedListSet, 
     /// # };
     /// #
-    /// let mut set: LinkedListSet<usize> = LinkedListSet::new();
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.push_back(list_index, 1);
     ///
-    /// assert_eq!(set.list_count(), 0);
-    /// let _ = set.new_list();
-    /// assert_ne!(set.list_count(), 0);
+    /// assert_eq!(set.try_pop_back(list_index), Ok(Some(1)));
+    /// assert_eq!(set.try_pop_back(list_index), Ok(None));
+    ///
+    /// set.remove_list(list_index);
+    ///
+    /// assert!(set.try_pop_back(list_index).is_err());
     /// ```
-    pub fn new_list(&mut self) -> ListIndex {
-        let new_list_index = self.alloc.new_index();
-        let new_list = LinkedList::new();
-        self.lists.insert(new_list_index, new_list);
+    pub fn try_pop_back(&mut self, list_index: ListIndex<Ix>) -> Result<Option<T>, ListSetError<Ix>> {
+        self.try_get_list(list_index)?;
 
-        new_list_index
+        Ok(self.pop_back(list_index))
     }
 
-    /// Determine whether the entire linked list set is empty.
+    /// Remove all elements from a linked list in the set.
     ///
     /// # Example
     ///
     /// ```
     /// # use list_set::{
-    /// #     LinkedListSet, 
+    /// #     LinkedListSet,  
     /// # };
     /// #
     /// let mut set = LinkedListSet::new();
     /// let list_index = set.new_list();
-    /// set.extend(list_index, vec![0, 1, 2, 3, 4, 5]);
-    /// 
-    /// assert!(!set.is_empty());
-    /// assert_ne!(set.list_count(), 0);
-    /// assert_ne!(set.node_count(), 0);
+    /// set.extend(
+    ///     list_index, 
+    ///     vec![String::from("spam"), String::from("eggs")
+    /// ]);
+    ///
     /// assert!(!set.list_is_empty(list_index));
     ///
-    /// set.clear_all();
+    /// set.clear(list_index);
     ///
-    /// assert!(set.is_empty());
+    /// assert!(set.list_is_empty(list_index));
     /// ```
-    pub fn is_empty(&self) -> bool {
-        self.lists.is_empty()
+    pub fn clear(&mut self, list_index: ListIndex<Ix>) {
+        while !self.list_is_empty(list_index) {
+            self.pop_front(list_index);
+        }
     }
 
-    /// Get an immutable reference to a linked list with the given index 
-    /// unchecked.
-    ///
-    /// # Panics
-    ///
-    /// Panics if a linked list with the list index `list_index` does not
-    /// exist in the set.
+    /// Remove all elements from a linked list in the set, or return an error
+    /// if the list index does not point to a linked list in the set.
     ///
     /// # Example
     ///
     /// ```
     /// # use list_set::{
-    /// #     LinkedListSet,
-    /// #     ListIndex,
+    /// #     LinkedListSet,  
     /// # };
     /// #
-    /// let mut set: LinkedListSet<usize> = LinkedListSet::new();
+    /// let mut set = LinkedListSet::new();
     /// let list_index = set.new_list();
-    /// let too_large_list_index = ListIndex::new(usize::MAX - 1);
-    /// 
-    /// let ok_result = std::panic::catch_unwind(|| {
-    ///     set.get_list_unchecked(list_index)
-    /// });
-    /// assert!(ok_result.is_ok());
+    /// set.extend(list_index, vec![1, 2, 3]);
     ///
-    /// let err_result = std::panic::catch_unwind(|| {
-    ///     set.get_list_unchecked(too_large_list_index)
-    /// });
-    /// assert!(err_result.is_err());
+    /// assert!(set.try_clear(list_index).is_ok());
+    /// assert!(set.list_is_empty(list_index));
+    ///
+    /// set.remove_list(list_index);
+    ///
+    /// assert!(set.try_clear(list_index).is_err());
     /// ```
-    #[inline]
-    pub fn get_list_unchecked(&self, list_index: ListIndex) -> &LinkedList<T> {
-        &self.lists[&list_index]
+    pub fn try_clear(&mut self, list_index: ListIndex<Ix>) -> Result<(), ListSetError<Ix>> {
+        self.try_get_list(list_index)?;
+        self.clear(list_index);
+
+        Ok(())
     }
 
-    /// Get an mutable reference to a linked list with the given index 
-    /// unchecked.
+    /// Remove a linked list and all its data from the set.
     ///
-    /// # Panics
+    /// Returns `true` if the list removed exists in the 
+    /// set prior to calling `remove_list`, and `false` otherwise. The 
+    /// `remove_list` function preserves the indices of all the other lists 
+    /// in the set. If `remove_list` returns false, no lists were affected.
     ///
-    /// Panics if a linked list with the list index `list_index` does not
-    /// exist in the set.
-    #[inline]
-    fn get_list_mut_unchecked(&mut self, list_index: ListIndex) -> &mut LinkedList<T> {
-        self.lists.get_mut(&list_index).unwrap()
+    /// The storage for the removed list gets reused by lists created 
+    /// afterwards, but the removed list index never points to one of them.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// #     ListIndex, 
+    /// # }; 
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_indices = [set.new_list(), set.new_list(), set.new_list()];
+    /// for (i, &list_index_i) in list_indices.iter().enumerate() {
+    ///     set.extend(list_index_i, vec![i; 5]);  
+    /// }
+    ///
+    /// assert!(list_indices.iter().all(|l| set.contains_list(*l)));
+    /// // Remove the list pointed to by `list_indices[0]`.
+    /// set.remove_list(list_indices[0]);
+    ///
+    /// assert!(!set.contains_list(list_indices[0]));
+    /// assert!(list_indices[1..].iter().all(|l| set.contains_list(*l)));
+    /// ```
+    pub fn remove_list(&mut self, list_index: ListIndex<Ix>) -> bool {
+        if self.contains_list(list_index) {
+            self.clear(list_index);
+            self.lists.remove(list_index);
+
+            true
+        } else {
+            false
+        }
     }
 
-    /// Get the linked list with the given index unchecked.
+    /// Remove a linked list from the set, and return its elements in list 
+    /// order.
     ///
-    /// # Panics
+    /// Returns `None` if the list index does not point to a linked list in 
+    /// the set, in which case no lists are affected.
     ///
-    /// Panics if a linked list with the list index `list_index` does not
-    /// exist in the set.
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.extend(list_index, vec![1, 2, 3]);
+    ///
+    /// assert_eq!(set.remove_list_into_vec(list_index), Some(vec![1, 2, 3]));
+    /// assert!(!set.contains_list(list_index));
+    /// assert_eq!(set.remove_list_into_vec(list_index), None);
+    /// ```
+    pub fn remove_list_into_vec(&mut self, list_index: ListIndex<Ix>) -> Option<Vec<T>> {
+        self.remove_list_drain(list_index).map(|drain| drain.collect())
+    }
+
+    /// Remove a linked list from the set, and return a draining iterator over
+    /// its elements in list order.
+    ///
+    /// Returns `None` if the list index does not point to a linked list in 
+    /// the set. Otherwise, the list is removed from the set when the iterator 
+    /// is dropped, along with any elements the iterator has not yielded.
     ///
     /// # Example
     ///
     /// ```
     /// # use list_set::{
     /// #     LinkedListSet,
-    /// #     ListIndex,
     /// # };
     /// #
-    /// let mut set: LinkedListSet<usize> = LinkedListSet::new();
+    /// let mut set = LinkedListSet::new();
     /// let list_index = set.new_list();
-    /// let too_large_list_index = ListIndex::new(usize::MAX - 1);
-    /// 
-    /// let ok_result = std::panic::catch_unwind(|| {
-    ///     set.get_list_unchecked(list_index)
-    /// });
-    /// assert!(ok_result.is_ok());
+    /// set.extend(list_index, vec![1, 2, 3]);
+    /// let result: Vec<usize> = set.remove_list_drain(list_index).unwrap().rev().collect();
     ///
-    /// let err_result = std::panic::catch_unwind(|| {
-    ///     set.get_list_unchecked(too_large_list_index)
-    /// });
-    /// assert!(err_result.is_err());
+    /// assert_eq!(result, vec![3, 2, 1]);
+    /// assert!(!set.contains_list(list_index));
     /// ```
-    #[inline]
-    pub fn get_list(&self, list_index: ListIndex) -> Option<&LinkedList<T>> {
+    pub fn remove_list_drain(&mut self, list_index: ListIndex<Ix>) -> Option<Drain<'_, T, Ix, S>> {
         if self.contains_list(list_index) {
-            Some(self.get_list_unchecked(list_index))
+            Some(Drain {
+                list_index: list_index,
+                remove_list: true,
+                set: self,
+            })
         } else {
             None
         }
     }
 
-    /// Get an immutable reference to a specific node from the linked list 
-    /// set unchecked.
+    /// Create a draining iterator that removes and yields the elements of a 
+    /// linked list in list order.
+    ///
+    /// The iterator is double-ended, so the elements can be drained from 
+    /// either end. The list remains in the set, and it is empty once the 
+    /// iterator is dropped, even if the iterator is dropped before yielding
+    /// every element.
     ///
     /// # Panics
     ///
-    /// Panics if the node index does not exist in the set.
+    /// Panics if `list_index` does not point to a linked list in the set.
     ///
-    /// # Note
-    /// Node indices are not stable betwen linked list set mutations.
-    #[inline]
-    fn get_node_unchecked(&self, node_index: NodeIndex) -> &Node<T> {
-        &self.nodes[node_index.0]
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.extend(list_index, vec![1, 2, 3, 4]);
+    /// let mut drain = set.drain(list_index);
+    ///
+    /// assert_eq!(drain.next(), Some(1));
+    /// assert_eq!(drain.next_back(), Some(4));
+    ///
+    /// drop(drain);
+    ///
+    /// assert!(set.contains_list(list_index));
+    /// assert!(set.list_is_empty(list_index));
+    /// ```
+    pub fn drain(&mut self, list_index: ListIndex<Ix>) -> Drain<'_, T, Ix, S> {
+        self.get_list_unchecked(list_index);
+
+        Drain {
+            list_index: list_index,
+            remove_list: false,
+            set: self,
+        }
     }
 
-    /// Get a mutable reference to a specific node from the linked list set unchecked.
+    /// Create a draining iterator that removes and yields every element in 
+    /// the set, along with the index of the list the element belonged to.
     ///
-    /// # Panics
+    /// The elements are yielded in the order of the underlying storage, not 
+    /// in list order. Every linked list remains in the set, and every list is
+    /// empty once the iterator is dropped. The set keeps the storage capacity 
+    /// for the elements.
     ///
-    /// Panics if the node index does not exist in the set.
-    #[inline]
-    fn get_node_mut_unchecked(&mut self, node_index: NodeIndex) -> &mut Node<T> {
-        &mut self.nodes[node_index.0]
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_indices = [set.new_list(), set.new_list()];
+    /// set.extend(list_indices[0], vec![1, 2]);
+    /// set.extend(list_indices[1], vec![3]);
+    /// let mut result: Vec<(usize, usize)> = set
+    ///     .drain_all()
+    ///     .map(|(list_index, item)| (list_indices.iter().position(|l| *l == list_index).unwrap(), item))
+    ///     .collect();
+    /// result.sort();
+    ///
+    /// assert_eq!(result, vec![(0, 1), (0, 2), (1, 3)]);
+    /// assert_eq!(set.node_count(), 0);
+    /// assert_eq!(set.list_count(), 2);
+    /// ```
+    pub fn drain_all(&mut self) -> DrainAll<'_, T, Ix> {
+        self.handles.clear();
+        self.lists.clear_lists();
+
+        let nodes = self.nodes.raw_mut();
+        // # Safety
+        // The iterator takes over the items, which stay in place in the 
+        // node storage until the storage grows again. That cannot happen 
+        // while the iterator mutably borrows the set.
+        unsafe {
+            self.nodes.forget_all();
+        }
+
+        DrainAll {
+            lists: &self.lists,
+            node_lists: nodes.node_lists,
+            items: nodes.items,
+            front: 0,
+            back: nodes.length,
+            _marker: PhantomData,
+        }
     }
 
-    /// Returns the length of the linked list indexed by `list_index`.
+    /// Convert the set into an owning iterator over the elements of one of 
+    /// its linked lists, in list order.
+    ///
+    /// The elements of the other lists are dropped along with the set when 
+    /// the iterator is dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `list_index` does not point to a linked list in the set.
     ///
     /// # Example
     ///
     /// ```
     /// # use list_set::{
-    /// #     LinkedListSet, 
+    /// #     LinkedListSet,
     /// # };
     /// #
     /// let mut set = LinkedListSet::new();
     /// let list_index = set.new_list();
-    /// 
-    /// assert_eq!(set.len(list_index), 0);
-    ///
-    /// set.push_back(list_index, 321);
+    /// set.extend(list_index, vec![String::from("spam"), String::from("eggs")]);
+    /// let result: Vec<String> = set.into_list_iter(list_index).collect();
     ///
-    /// assert_ne!(set.len(list_index), 0);
+    /// assert_eq!(result, vec![String::from("spam"), String::from("eggs")]);
     /// ```
-    pub fn len(&self, list_index: ListIndex) -> usize {
-        self.get_list_unchecked(list_index).len()
+    pub fn into_list_iter(self, list_index: ListIndex<Ix>) -> ListIntoIter<T, Ix, S> {
+        self.get_list_unchecked(list_index);
+
+        ListIntoIter {
+            list_index: list_index,
+            set: self,
+        }
     }
 
-    /// Determine whether a particular linked list in the set is empty.
+    /// Convert the set into a vector of its linked lists, each paired with 
+    /// its list index.
+    ///
+    /// The lists appear in the same order as `list_indices`, and the elements
+    /// of each list appear in list order. The items are moved out of the set
+    /// rather than cloned.
     ///
     /// # Example
     ///
     /// ```
     /// # use list_set::{
-    /// #     LinkedListSet, 
+    /// #     LinkedListSet,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_indices = [set.new_list(), set.new_list()];
+    /// set.extend(list_indices[0], vec![1, 2, 3]);
+    /// set.push_front(list_indices[1], 4);
+    /// let result = set.into_vecs();
+    ///
+    /// assert_eq!(result, vec![(list_indices[0], vec![1, 2, 3]), (list_indices[1], vec![4])]);
+    /// ```
+    pub fn into_vecs(mut self) -> Vec<(ListIndex<Ix>, Vec<T>)> {
+        let list_indices: Vec<ListIndex<Ix>> = self.list_indices().collect();
+        let mut lists = Vec::with_capacity(list_indices.len());
+        for list_index in list_indices {
+            let list: Vec<T> = self.drain(list_index).collect();
+            lists.push((list_index, list));
+        }
+
+        lists
+    }
+
+    /// Extend a linked list with an iterable collection of items.
+    ///
+    /// # Example
+    /// 
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// #     
     /// # };
     /// #
     /// let mut set = LinkedListSet::new();
     /// let list_index = set.new_list();
+    /// let expected = vec![0, 1, 2, 3, 4, 5];
     /// 
-    /// assert!(set.list_is_empty(list_index));
-    ///
-    /// set.push_back(list_index, 321);
+    /// set.extend(list_index, expected.iter().copied());
+    /// 
+    /// let result: Vec<usize> = set.iter(list_index).copied().collect();
     ///
-    /// assert!(!set.list_is_empty(list_index));
+    /// assert_eq!(result, expected);
     /// ```
-    pub fn list_is_empty(&self, list_index: ListIndex) -> bool {
-        self.get_list_unchecked(list_index).is_empty()
+    pub fn extend<I>(&mut self, list_index: ListIndex<Ix>, items: I)
+    where
+        I: IntoIterator<Item = T>
+    {
+        for item in items.into_iter() {
+            self.push_back(list_index, item);
+        }
     }
 
-    /// Returns the number of linked lists in the set.
+    /// Extend a linked list with an iterable collection of items, or return 
+    /// an error if the list index does not point to a linked list in the set.
     ///
-    /// # Example
+    /// Unlike `extend`, this function also returns an error instead of 
+    /// aborting if the set fails to allocate storage for the items. The items 
+    /// pushed to the list before the allocation failure remain in the list.
     ///
+    /// # Example
+    /// 
     /// ```
     /// # use list_set::{
-    /// #     LinkedListSet, 
+    /// #     LinkedListSet,
     /// # };
     /// #
-    /// let mut set: LinkedListSet<usize> = LinkedListSet::new();
-    /// let list_index0 = set.new_list();
-    /// let list_index1 = set.new_list();
-    /// let list_index2 = set.new_list();
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
     /// 
-    /// assert_eq!(set.list_count(), 3);
+    /// assert!(set.try_extend(list_index, vec![1, 2, 3]).is_ok());
+    /// assert_eq!(set.len(list_index), 3);
+    ///
+    /// set.remove_list(list_index);
+    ///
+    /// assert!(set.try_extend(list_index, vec![4, 5, 6]).is_err());
     /// ```
-    pub fn list_count(&self) -> usize {
-        self.lists.len()
+    pub fn try_extend<I>(&mut self, list_index: ListIndex<Ix>, items: I) -> Result<(), ListSetError<Ix>>
+    where
+        I: IntoIterator<Item = T>
+    {
+        self.try_get_list(list_index)?;
+        let items = items.into_iter();
+        let (lower_bound, _) = items.size_hint();
+        self.try_reserve(lower_bound)?;
+        for item in items {
+            self.try_push_back(list_index, item)?;
+        }
+
+        Ok(())
     }
 
-    /// Returns the maximum number items across all lists combined that the
-    /// set can currently hold.
+    /// Move all the elements of the linked list `source_index` to the back 
+    /// of the linked list `target_index`.
     ///
-    /// The function returns the number of items the collection currently *can* hold,
-    /// *not* the number of items the function currently holds. For that, use the
-    /// `node_count` function.
+    /// The source list is left empty, but remains in the set. The elements 
+    /// are relinked in place, so no items get moved in the underlying 
+    /// storage, and the node handles of the moved elements remain valid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `source_index` and `target_index` are the same list, or
+    /// either of them does not point to a linked list in the set.
     ///
     /// # Example
     ///
     /// ```
     /// # use list_set::{
-    /// #     LinkedListSet, 
+    /// #     LinkedListSet,
     /// # };
     /// #
-    /// let expected_node_capacity = 3000;
-    /// let mut set: LinkedListSet<usize> = LinkedListSet::with_capacity(
-    ///     expected_node_capacity
-    /// );
-    /// 
-    /// assert_eq!(set.node_capacity(), expected_node_capacity);
+    /// let mut set = LinkedListSet::new();
+    /// let target_index = set.new_list();
+    /// let source_index = set.new_list();
+    /// set.extend(target_index, vec![1, 2, 3]);
+    /// set.extend(source_index, vec![4, 5, 6]);
+    /// set.append(target_index, source_index);
+    /// let result: Vec<usize> = set.iter(target_index).copied().collect();
+    ///
+    /// assert_eq!(result, vec![1, 2, 3, 4, 5, 6]);
+    /// assert!(set.contains_list(source_index));
+    /// assert!(set.list_is_empty(source_index));
     /// ```
-    pub fn node_capacity(&self) -> usize {
-        self.nodes.capacity()
+    pub fn append(&mut self, target_index: ListIndex<Ix>, source_index: ListIndex<Ix>) {
+        assert_ne!(source_index, target_index, "cannot append a linked list to itself");
+        let previous_index = self.get_list_unchecked(target_index).back;
+        self.splice_list_nodes(source_index, target_index, previous_index, NodeIndex::end());
     }
 
-    /// Returns the number of linked list nodes in the set.
+    /// Move all the elements of the linked list `source_index` to the front 
+    /// of the linked list `target_index`.
+    ///
+    /// The source list is left empty, but remains in the set. The elements 
+    /// are relinked in place, so no items get moved in the underlying 
+    /// storage, and the node handles of the moved elements remain valid.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `source_index` and `target_index` are the same list, or
+    /// either of them does not point to a linked list in the set.
     ///
     /// # Example
     ///
     /// ```
     /// # use list_set::{
-    /// #     LinkedListSet, 
+    /// #     LinkedListSet,
     /// # };
     /// #
     /// let mut set = LinkedListSet::new();
-    /// let list_index0 = set.new_list();
-    /// let list_index1 = set.new_list();
-    /// let list_index2 = set.new_list();
-    /// set.extend(list_index0, vec![0, 1, 2, 3, 4, 5]);
-    /// set.extend(list_index1, vec![6, 7, 8, 9, 10, 11]);
-    /// set.extend(list_index2, vec![12, 13, 14, 15, 16, 17]);
+    /// let target_index = set.new_list();
+    /// let source_index = set.new_list();
+    /// set.extend(target_index, vec![4, 5, 6]);
+    /// set.extend(source_index, vec![1, 2, 3]);
+    /// set.prepend(target_index, source_index);
+    /// let result: Vec<usize> = set.iter(target_index).copied().collect();
     ///
-    /// assert_eq!(
-    ///     set.node_count(), 
-    ///     set.len(list_index0) + set.len(list_index1) + set.len(list_index2)
-    /// );
+    /// assert_eq!(result, vec![1, 2, 3, 4, 5, 6]);
+    /// assert!(set.contains_list(source_index));
+    /// assert!(set.list_is_empty(source_index));
     /// ```
-    pub fn node_count(&self) -> usize {
-        self.nodes.len()
+    pub fn prepend(&mut self, target_index: ListIndex<Ix>, source_index: ListIndex<Ix>) {
+        assert_ne!(source_index, target_index, "cannot prepend a linked list to itself");
+        let next_index = self.get_list_unchecked(target_index).front;
+        self.splice_list_nodes(source_index, target_index, NodeIndex::end(), next_index);
     }
 
-    /// Determine whether a linked list contains a particular item.
+    /// Split a linked list into two at the given position.
     ///
-    /// Returns `true` if the linked list contains an instance of `item`, and 
-    /// `false` if the linked list does not contain and instance of `item`.
+    /// The elements at positions `at` and after move into a new linked list 
+    /// in the set, and the index of the new list is returned. The elements
+    /// are relinked in place, so their node handles remain valid. If `at` 
+    /// equals the length of the list, the new list is empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at` is greater than the length of the list, or `list_index`
+    /// does not point to a linked list in the set.
     ///
     /// # Example
     ///
     /// ```
     /// # use list_set::{
-    /// #     LinkedListSet,  
+    /// #     LinkedListSet,
     /// # };
     /// #
     /// let mut set = LinkedListSet::new();
     /// let list_index = set.new_list();
-    /// set.extend(
-    ///     list_index, 
-    ///     vec![String::from("spam"), String::from("eggs")]
-    /// );
+    /// set.extend(list_index, vec![1, 2, 3, 4, 5]);
+    /// let tail_index = set.split_off(list_index, 2);
+    /// let head: Vec<usize> = set.iter(list_index).copied().collect();
+    /// let tail: Vec<usize> = set.iter(tail_index).copied().collect();
     ///
-    /// assert!(set.contains(list_index, &String::from("spam")));
-    /// assert!(set.contains(list_index, &String::from("eggs")));
-    /// assert!(!set.contains(list_index, &String::from("pancakes")));
+    /// assert_eq!(head, vec![1, 2]);
+    /// assert_eq!(tail, vec![3, 4, 5]);
     /// ```
+    pub fn split_off(&mut self, list_index: ListIndex<Ix>, at: usize) -> ListIndex<Ix> {
+        let length = self.len(list_index);
+        assert!(at <= length, "cannot split off at a nonexistent index");
+        if at == length {
+            return self.split_list_nodes(list_index, NodeIndex::end(), NodeIndex::end());
+        }
+
+        let first_index = self.find_list_node(list_index, at);
+        let last_index = self.get_list_unchecked(list_index).back;
+
+        self.split_list_nodes(list_index, first_index, last_index)
+    }
+
+    /// Move the element at position `at` in the linked list `source_index` 
+    /// to the back of the linked list `target_index`.
     ///
-    /// If you do not have an instance of `&T`, but just an `&U` such that `T: Borrow<U>`
-    /// (for example, `String: Borrow<str>`), you can also use the `any()` function from
-    /// the iterator trait.
+    /// The element is relinked in place, so its item does not move in the 
+    /// underlying storage. Returns the handle of the moved element, or `None`
+    /// if `at` falls outside the length of the source list. The target list
+    /// may be the same as the source list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either list index does not point to a linked list in the 
+    /// set.
+    ///
+    /// # Example
     ///
     /// ```
     /// # use list_set::{
-    /// #     LinkedListSet,  
+    /// #     LinkedListSet,
     /// # };
     /// #
     /// let mut set = LinkedListSet::new();
-    /// let list_index = set.new_list();
-    /// set.extend(
-    ///     list_index, 
-    ///     vec![String::from("spam"), String::from("eggs")]
-    /// );
+    /// let source_index = set.new_list();
+    /// let target_index = set.new_list();
+    /// set.extend(source_index, vec![1, 2, 3]);
+    /// set.extend(target_index, vec![4, 5]);
+    /// let handle = set.move_to_back(source_index, 1, target_index).unwrap();
+    /// let source: Vec<usize> = set.iter(source_index).copied().collect();
+    /// let target: Vec<usize> = set.iter(target_index).copied().collect();
     ///
-    /// assert!(set.iter(list_index).any(|item| item == "spam"));
-    /// assert!(set.iter(list_index).any(|item| item == "eggs"));
-    /// assert!(!set.iter(list_index).any(|item| item == "pancakes"));
+    /// assert_eq!(source, vec![1, 3]);
+    /// assert_eq!(target, vec![4, 5, 2]);
+    /// assert_eq!(set.get(handle), Some(&2));
     /// ```
-    pub fn contains(&self, list_index: ListIndex, item: &T) -> bool
-    where
-        T: PartialEq<T>
-    {
-        let mut current_index = self.get_list_unchecked(list_index).front;
-        while current_index != NodeIndex::end() {
-            let current_node = self.get_node_unchecked(current_index);
-            if current_node.item() == item {
-                return true;
-            } else {
-                current_index = current_node.next();
-            }
+    pub fn move_to_back(&mut self, source_index: ListIndex<Ix>, at: usize, target_index: ListIndex<Ix>) -> Option<NodeHandle> {
+        let node_index = self.find_list_node(source_index, at);
+        if node_index == NodeIndex::end() {
+            return None;
         }
 
-        false
+        self.move_list_node(node_index, target_index, false);
+
+        Some(self.node_handle(node_index))
     }
 
-    /// Determine whether a linked list set contains a linked list with the 
-    /// index given by `list_index`.
+    /// Move the element at position `at` in the linked list `source_index` 
+    /// to the front of the linked list `target_index`.
+    ///
+    /// The element is relinked in place, so its item does not move in the 
+    /// underlying storage. Returns the handle of the moved element, or `None`
+    /// if `at` falls outside the length of the source list. The target list
+    /// may be the same as the source list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either list index does not point to a linked list in the 
+    /// set.
     ///
     /// # Example
-    /// 
+    ///
     /// ```
     /// # use list_set::{
     /// #     LinkedListSet,
-    /// #     ListIndex,
     /// # };
     /// #
-    /// let mut set: LinkedListSet<usize> = LinkedListSet::new();
-    /// let list_index = set.new_list();
-    /// 
-    /// assert!(set.contains_list(list_index));
-    /// assert!(!set.contains_list(ListIndex::new(usize::MAX - 1)));
+    /// let mut set = LinkedListSet::new();
+    /// let source_index = set.new_list();
+    /// let target_index = set.new_list();
+    /// set.extend(source_index, vec![1, 2, 3]);
+    /// set.extend(target_index, vec![4, 5]);
+    /// set.move_to_front(source_index, 2, target_index);
+    /// let source: Vec<usize> = set.iter(source_index).copied().collect();
+    /// let target: Vec<usize> = set.iter(target_index).copied().collect();
+    ///
+    /// assert_eq!(source, vec![1, 2]);
+    /// assert_eq!(target, vec![3, 4, 5]);
     /// ```
-    pub fn contains_list(&self, list_index: ListIndex) -> bool {
-        self.lists.contains_key(&list_index)
+    pub fn move_to_front(&mut self, source_index: ListIndex<Ix>, at: usize, target_index: ListIndex<Ix>) -> Option<NodeHandle> {
+        let node_index = self.find_list_node(source_index, at);
+        if node_index == NodeIndex::end() {
+            return None;
+        }
+
+        self.move_list_node(node_index, target_index, true);
+
+        Some(self.node_handle(node_index))
     }
 
-    /// Provide an immutable forward iterator for a linked list with the 
-    /// index `list_index` inside the linked list set.
+    /// Move the element a node handle points to onto the back of the linked
+    /// list `target_index`.
+    ///
+    /// Returns `false` if the handle is no longer valid. Moving an element
+    /// through its handle takes constant time, and the handle remains valid
+    /// afterwards.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `target_index` does not point to a linked list in the set.
     ///
     /// # Example
-    /// 
+    ///
     /// ```
     /// # use list_set::{
     /// #     LinkedListSet,
     /// # };
     /// #
     /// let mut set = LinkedListSet::new();
-    /// let list_index = set.new_list();
-    /// let expected = vec![
-    ///     String::from("spam"), 
-    ///     String::from("eggs"), 
-    ///     String::from("pancakes")
-    /// ];
-    /// set.extend(list_index, expected.iter().cloned());
-    /// 
-    /// assert!(set.iter(list_index).enumerate().all(|(i, item_i)| {
-    ///     item_i == &expected[i]
-    /// }));
+    /// let source_index = set.new_list();
+    /// let target_index = set.new_list();
+    /// let handle = set.push_back(source_index, 1);
+    /// set.push_back(target_index, 2);
+    ///
+    /// assert!(set.move_node_to_back(handle, target_index));
+    ///
+    /// let target: Vec<usize> = set.iter(target_index).copied().collect();
+    ///
+    /// assert!(set.list_is_empty(source_index));
+    /// assert_eq!(target, vec![2, 1]);
     /// ```
-    pub fn iter(&self, list_index: ListIndex) -> ListIter<T> {
-        ListIter {
-            current_front: self.get_list_unchecked(list_index).front,
-            current_back: self.get_list_unchecked(list_index).back,
-            position_front: 0,
-            position_back: 0,
-            list: self.get_list_unchecked(list_index).clone(),
-            nodes: &self.nodes,
+    pub fn move_node_to_back(&mut self, handle: NodeHandle, target_index: ListIndex<Ix>) -> bool {
+        match self.handles.get(handle, self.lists.owner) {
+            Some(node_index) => {
+                self.move_list_node(node_index, target_index, false);
+                true
+            }
+            None => false,
         }
     }
 
-    /// Provide a mutable forward iterator for a linked list with the 
-    /// index `list_index` inside the linked list set.
+    /// Move the element a node handle points to onto the front of the linked
+    /// list `target_index`.
+    ///
+    /// Returns `false` if the handle is no longer valid. Moving an element
+    /// through its handle takes constant time, and the handle remains valid
+    /// afterwards.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `target_index` does not point to a linked list in the set.
     ///
     /// # Example
-    /// 
+    ///
     /// ```
     /// # use list_set::{
     /// #     LinkedListSet,
     /// # };
     /// #
     /// let mut set = LinkedListSet::new();
-    /// let list_index = set.new_list();
-    /// let data = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10];
-    /// set.extend(list_index, data);
-    /// 
-    /// for item in set.iter_mut(list_index) {
-    ///     *item = 2 * (*item);
-    /// }
+    /// let source_index = set.new_list();
+    /// let target_index = set.new_list();
+    /// let handle = set.push_back(source_index, 1);
+    /// set.push_back(target_index, 2);
     ///
-    /// let expected = vec![2, 4, 6, 8, 10, 12, 14, 16, 18, 20];
-    /// let result: Vec<usize> = set.iter(list_index).copied().collect();
+    /// assert!(set.move_node_to_front(handle, target_index));
     ///
-    /// assert_eq!(result, expected);
+    /// let target: Vec<usize> = set.iter(target_index).copied().collect();
+    ///
+    /// assert!(set.list_is_empty(source_index));
+    /// assert_eq!(target, vec![1, 2]);
     /// ```
-    pub fn iter_mut(&mut self, list_index: ListIndex) -> ListIterMut<T> {
-        ListIterMut {
-            current_front: self.get_list_unchecked(list_index).front,
-            current_back: self.get_list_unchecked(list_index).back,
-            position_front: 0,
-            position_back: 0,
-            list: self.get_list_unchecked(list_index).clone(),
-            nodes: &mut self.nodes,
+    pub fn move_node_to_front(&mut self, handle: NodeHandle, target_index: ListIndex<Ix>) -> bool {
+        match self.handles.get(handle, self.lists.owner) {
+            Some(node_index) => {
+                self.move_list_node(node_index, target_index, true);
+                true
+            }
+            None => false,
         }
     }
 
-    /// Provide an iterator over the handles of the linked lists stored inside
-    /// the set.
+    /// Sort a linked list in ascending order.
+    ///
+    /// The sort is stable, so equal elements keep their relative order. The
+    /// list is sorted by relinking its nodes, so no items move in the 
+    /// underlying storage, and the node handles remain valid. The sort runs 
+    /// in **O(n log n)** time and allocates nothing.
     ///
     /// # Example
     ///
     /// ```
     /// # use list_set::{
-    /// #    LinkedListSet,    
+    /// #     LinkedListSet,
     /// # };
     /// #
-    /// let mut set: LinkedListSet<usize> = LinkedListSet::new();
-    /// set.new_list();
-    /// set.new_list();
-    /// set.new_list();
-    /// set.new_list();
-    /// set.new_list();
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.extend(list_index, vec![5, 3, 1, 4, 2]);
+    /// set.sort(list_index);
+    /// let result: Vec<usize> = set.iter(list_index).copied().collect();
     ///
-    /// assert!(set.list_indices().all(|list_index| {
-    ///     set.contains_list(list_index)  
-    /// }));
+    /// assert_eq!(result, vec![1, 2, 3, 4, 5]);
     /// ```
-    pub fn list_indices(&self) -> ListIndices<T> {
-        ListIndices {
-            iter: self.lists.keys(),
-        }
+    pub fn sort(&mut self, list_index: ListIndex<Ix>)
+    where
+        T: Ord
+    {
+        self.sort_list_nodes(list_index, T::cmp);
     }
 
-    /// Clear the entire linked list set.
+    /// Sort a linked list with a comparison function.
     ///
-    /// This operation deletes all the nodes and all the linked lists contained in the
-    /// set.
+    /// The sort is stable, so equal elements keep their relative order. The
+    /// list is sorted by relinking its nodes, so no items move in the 
+    /// underlying storage, and the node handles remain valid.
+    ///
+    /// # Example
     ///
     /// ```
     /// # use list_set::{
-    /// #     LinkedListSet,  
+    /// #     LinkedListSet,
     /// # };
     /// #
     /// let mut set = LinkedListSet::new();
     /// let list_index = set.new_list();
-    /// set.extend(
-    ///     list_index, 
-    ///     vec![String::from("spam"), String::from("eggs"), String::from("pancakes")]
-    /// );
+    /// set.extend(list_index, vec![5, 3, 1, 4, 2]);
+    /// set.sort_by(list_index, |a, b| b.cmp(a));
+    /// let result: Vec<usize> = set.iter(list_index).copied().collect();
     ///
-    /// assert!(!set.is_empty());
-    /// set.clear_all();
-    /// assert!(set.is_empty());
+    /// assert_eq!(result, vec![5, 4, 3, 2, 1]);
     /// ```
-    pub fn clear_all(&mut self) {
-        self.nodes.clear();
-        self.lists.clear();
+    pub fn sort_by<F>(&mut self, list_index: ListIndex<Ix>, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering
+    {
+        self.sort_list_nodes(list_index, compare);
     }
 
-    /// Provides an immutable reference to the front element of a list, or
-    /// `None` if the list is empty.
+    /// Sort a linked list with a key extraction function.
+    ///
+    /// The sort is stable, so elements with equal keys keep their relative 
+    /// order. The key function is called twice per comparison.
     ///
     /// # Example
     ///
     /// ```
     /// # use list_set::{
-    /// #     LinkedListSet, 
+    /// #     LinkedListSet,
     /// # };
     /// #
     /// let mut set = LinkedListSet::new();
     /// let list_index = set.new_list();
+    /// set.extend(list_index, vec![(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd')]);
+    /// set.sort_by_key(list_index, |item| item.0);
+    /// let result: Vec<(usize, char)> = set.iter(list_index).copied().collect();
     ///
-    /// assert!(set.front(list_index).is_none());
-    ///
-    /// set.extend(
-    ///     list_index, 
-    ///     vec![String::from("spam"), String::from("eggs"), String::from("pancakes")]
-    /// );
-    ///
-    /// assert_eq!(set.front(list_index).map(|p| p.as_str()), Some("spam"));
+    /// assert_eq!(result, vec![(1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]);
     /// ```
-    pub fn front(&self, list_index: ListIndex) -> Option<&T> {
-        let front_node_index = {
-            let list = self.get_list(list_index)?;
-            list.front
-        };
-        if front_node_index != NodeIndex::end() {
-            let node = self.get_node_unchecked(front_node_index);
-
-            Some(node.item())
-        } else {
-            None
-        }
+    pub fn sort_by_key<K, F>(&mut self, list_index: ListIndex<Ix>, mut f: F)
+    where
+        F: FnMut(&T) -> K,
+        K: Ord
+    {
+        self.sort_list_nodes(list_index, |a, b| f(a).cmp(&f(b)));
     }
 
-    /// Provides a mutable reference to the front element of a list, or
-    /// `None` if the list is empty.
+    /// Sort a linked list with a comparison function.
+    ///
+    /// This is an alias of `sort_by`, kept for parity with the slice API. It 
+    /// runs the same stable merge sort, so equal elements keep their 
+    /// relative order.
     ///
     /// # Example
     ///
     /// ```
     /// # use list_set::{
-    /// #     LinkedListSet, 
+    /// #     LinkedListSet,
     /// # };
     /// #
     /// let mut set = LinkedListSet::new();
     /// let list_index = set.new_list();
+    /// set.extend(list_index, vec![5, 3, 1, 4, 2]);
+    /// set.sort_unstable_by(list_index, |a, b| a.cmp(b));
+    /// let result: Vec<usize> = set.iter(list_index).copied().collect();
     ///
-    /// assert!(set.back_mut(list_index).is_none());
-    ///
-    /// set.extend(
-    ///     list_index, 
-    ///     vec![String::from("spam"), String::from("eggs"), String::from("pancakes")]
-    /// );
-    ///
-    /// assert_eq!(set.front_mut(list_index).map(|p| p.as_str()), Some("spam"));
-    /// {
-    ///     let mut front = set.front_mut(list_index).unwrap();
-    ///     *front = String::from("bacon");
-    /// }
-    /// assert_eq!(set.front(list_index).map(|p| p.as_str()), Some("bacon"));
+    /// assert_eq!(result, vec![1, 2, 3, 4, 5]);
     /// ```
-    pub fn front_mut(&mut self, list_index: ListIndex) -> Option<&mut T> {
-        let front_node_index = {
-            let list = self.get_list(list_index)?;
-            list.front
-        };
-        if front_node_index != NodeIndex::end() {
-            let node = self.get_node_mut_unchecked(front_node_index);
-
-            Some(node.item_mut())
-        } else {
-            None
-        }
+    pub fn sort_unstable_by<F>(&mut self, list_index: ListIndex<Ix>, compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering
+    {
+        self.sort_list_nodes(list_index, compare);
     }
 
-    /// Provides an immutable reference to the back element of a list, or
-    /// `None` if the list is empty.
+    /// Determine whether the elements of a linked list are sorted in 
+    /// ascending order.
     ///
     /// # Example
     ///
     /// ```
     /// # use list_set::{
-    /// #     LinkedListSet, 
+    /// #     LinkedListSet,
     /// # };
     /// #
     /// let mut set = LinkedListSet::new();
     /// let list_index = set.new_list();
+    /// set.extend(list_index, vec![1, 2, 2, 3]);
     ///
-    /// assert!(set.back(list_index).is_none());
+    /// assert!(set.is_sorted(list_index));
     ///
-    /// set.extend(
-    ///     list_index, 
-    ///     vec![String::from("spam"), String::from("eggs"), String::from("pancakes")]
-    /// );
+    /// set.push_back(list_index, 0);
     ///
-    /// assert_eq!(set.back(list_index).map(|p| p.as_str()), Some("pancakes"));
+    /// assert!(!set.is_sorted(list_index));
     /// ```
-    pub fn back(&self, list_index: ListIndex) -> Option<&T> {
-        let back_node_index = {
-            let list = self.get_list(list_index)?;
-            list.back
+    pub fn is_sorted(&self, list_index: ListIndex<Ix>) -> bool
+    where
+        T: PartialOrd
+    {
+        let mut iter = self.iter(list_index);
+        let mut previous = match iter.next() {
+            Some(item) => item,
+            None => return true,
         };
-        if back_node_index != NodeIndex::end() {
-            let node = self.get_node_unchecked(back_node_index);
+        for item in iter {
+            match previous.partial_cmp(item) {
+                Some(Ordering::Less) | Some(Ordering::Equal) => {}
+                _ => return false,
+            }
 
-            Some(node.item())
-        } else {
-            None
+            previous = item;
         }
+
+        true
     }
 
-    /// Provides a mutable reference to the back element of a list, or
-    /// `None` if the list is empty.
+    /// Insert an item into a sorted linked list, keeping the list sorted in 
+    /// ascending order.
+    ///
+    /// The item goes after every element that compares equal to it, so 
+    /// repeated insertions are stable. The list is searched from the back, 
+    /// so inserting items in ascending order takes constant time. Returns a 
+    /// handle to the new element.
     ///
     /// # Example
     ///
     /// ```
     /// # use list_set::{
-    /// #     LinkedListSet, 
+    /// #     LinkedListSet,
     /// # };
     /// #
     /// let mut set = LinkedListSet::new();
     /// let list_index = set.new_list();
+    /// for item in [3, 1, 4, 1, 5, 9, 2, 6] {
+    ///     set.insert_sorted(list_index, item);
+    /// }
+    /// let result: Vec<usize> = set.iter(list_index).copied().collect();
     ///
-    /// assert!(set.back_mut(list_index).is_none());
+    /// assert_eq!(result, vec![1, 1, 2, 3, 4, 5, 6, 9]);
+    /// ```
+    pub fn insert_sorted(&mut self, list_index: ListIndex<Ix>, item: T) -> NodeHandle
+    where
+        T: Ord
+    {
+        self.insert_sorted_by(list_index, item, T::cmp)
+    }
+
+    /// Insert an item into a linked list sorted with respect to a comparison
+    /// function, keeping the list sorted.
     ///
-    /// set.extend(
-    ///     list_index, 
-    ///     vec![String::from("spam"), String::from("eggs"), String::from("pancakes")]
-    /// );
+    /// The item goes after every element that compares equal to it, so 
+    /// repeated insertions are stable. Returns a handle to the new element.
     ///
-    /// assert_eq!(set.back_mut(list_index).map(|p| p.as_str()), Some("pancakes"));
-    /// {
-    ///     let mut back = set.back_mut(list_index).unwrap();
-    ///     *back = String::from("waffles");
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// for item in [3, 1, 4, 1, 5] {
+    ///     set.insert_sorted_by(list_index, item, |a, b| b.cmp(a));
     /// }
-    /// assert_eq!(set.back(list_index).map(|p| p.as_str()), Some("waffles"));
+    /// let result: Vec<usize> = set.iter(list_index).copied().collect();
+    ///
+    /// assert_eq!(result, vec![5, 4, 3, 1, 1]);
     /// ```
-    pub fn back_mut(&mut self, list_index: ListIndex) -> Option<&mut T> {
-        let back_node_index = {
-            let list = self.get_list(list_index)?;
-            list.back
-        };
-        if back_node_index != NodeIndex::end() {
-            let node = self.get_node_mut_unchecked(back_node_index);
-
-            Some(node.item_mut())
-        } else {
-            None
-        }
-    }
+    pub fn insert_sorted_by<F>(&mut self, list_index: ListIndex<Ix>, item: T, mut compare: F) -> NodeHandle
+    where
+        F: FnMut(&T, &T) -> Ordering
+    {
+        let mut previous_index = self.get_list_unchecked(list_index).back;
+        while previous_index != NodeIndex::end() {
+            if compare(self.get_item_unchecked(previous_index), &item) != Ordering::Greater {
+                break;
+            }
 
-    /// Link a new node into a linked list in the set.
-    #[inline]
-    fn link_list_node(
-        &mut self, 
-        node_index: NodeIndex, 
-        previous_index: NodeIndex, 
-        next_index: NodeIndex
-    ) {
-        {
-            let node = self.get_node_mut_unchecked(node_index);
-            node.previous = previous_index;
-            node.next = next_index;
+            previous_index = self.get_node_unchecked(previous_index).previous();
         }
 
-        if next_index != NodeIndex::end() {
-            let next_node = self.get_node_mut_unchecked(next_index);
-            next_node.previous = node_index;
-        }
+        let next_index = if previous_index != NodeIndex::end() {
+            self.get_node_unchecked(previous_index).next()
+        } else {
+            self.get_list_unchecked(list_index).front
+        };
 
-        if previous_index != NodeIndex::end() {
-            let previous_node = self.get_node_mut_unchecked(previous_index);
-            previous_node.next = node_index;
-        }
+        self.insert_list_node(list_index, previous_index, next_index, item)
     }
 
-    /// Push an item to the back of a linked list in a linked list set.
+    /// Merge the sorted linked list `source_index` into the sorted linked 
+    /// list `target_index`, keeping the target list sorted in ascending order.
+    ///
+    /// The merge takes linear time in the combined length of the lists, and 
+    /// relinks the nodes of the source list in place, so node handles remain
+    /// valid. When elements compare equal, the elements of the target list 
+    /// come first. The source list is left empty, but remains in the set. If 
+    /// a comparison panics, the elements merged so far stay in the target 
+    /// list, and the rest stay in the source list.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `source_index` and `target_index` are the same list, or
+    /// either of them does not point to a linked list in the set.
     ///
     /// # Example
     ///
     /// ```
     /// # use list_set::{
-    /// #     LinkedListSet,  
+    /// #     LinkedListSet,
     /// # };
     /// #
     /// let mut set = LinkedListSet::new();
-    /// let list_index = set.new_list();
-    ///
-    /// assert!(set.back(list_index).is_none());
-    ///
-    /// set.extend(list_index, vec![0, 1, 2, 3, 4, 5]);
-    /// 
-    /// assert_eq!(set.back(list_index), Some(&5));
-    //
-    /// set.push_back(list_index, 300);
+    /// let target_index = set.new_list();
+    /// let source_index = set.new_list();
+    /// set.extend(target_index, vec![1, 3, 5, 7]);
+    /// set.extend(source_index, vec![0, 2, 3, 8]);
+    /// set.merge_sorted(target_index, source_index);
+    /// let result: Vec<usize> = set.iter(target_index).copied().collect();
     ///
-    /// assert_eq!(set.back(list_index), Some(&300));
+    /// assert_eq!(result, vec![0, 1, 2, 3, 3, 5, 7, 8]);
+    /// assert!(set.list_is_empty(source_index));
     /// ```
-    pub fn push_back(&mut self, list_index: ListIndex, item: T) {
-        let new_node = Node::new(list_index, item);
-        let new_node_index = NodeIndex::new(self.nodes.len());
-        self.nodes.push(new_node);
-        if self.get_list_unchecked(list_index).is_empty() {
-            let list = self.get_list_mut_unchecked(list_index);
-            list.front = new_node_index;
-            list.back = new_node_index;
-        } else {
-            let back = self.get_list_unchecked(list_index).back;
-            self.link_list_node(
-                new_node_index, 
-                back, 
-                NodeIndex::end()
-            );
-            self.get_list_mut_unchecked(list_index).back = new_node_index;
-        }
-
-        self.get_list_mut_unchecked(list_index).length += 1;
+    pub fn merge_sorted(&mut self, target_index: ListIndex<Ix>, source_index: ListIndex<Ix>)
+    where
+        T: Ord
+    {
+        self.merge_sorted_by(target_index, source_index, T::cmp);
     }
 
-    /// Push an item to the front of a linked list in a linked list set.
+    /// Merge the linked list `source_index` into the linked list 
+    /// `target_index`, where both lists are sorted with respect to a 
+    /// comparison function.
+    ///
+    /// See `merge_sorted` for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `source_index` and `target_index` are the same list, or
+    /// either of them does not point to a linked list in the set.
     ///
     /// # Example
     ///
     /// ```
     /// # use list_set::{
-    /// #     LinkedListSet,  
+    /// #     LinkedListSet,
     /// # };
     /// #
     /// let mut set = LinkedListSet::new();
-    /// let list_index = set.new_list();
-    ///
-    /// assert!(set.front(list_index).is_none());
-    ///
-    /// set.extend(list_index, vec![0, 1, 2, 3, 4, 5]);
-    /// 
-    /// assert_eq!(set.front(list_index), Some(&0));
-    //
-    /// set.push_front(list_index, 300);
+    /// let target_index = set.new_list();
+    /// let source_index = set.new_list();
+    /// set.extend(target_index, vec![7, 5, 3]);
+    /// set.extend(source_index, vec![8, 4, 0]);
+    /// set.merge_sorted_by(target_index, source_index, |a, b| b.cmp(a));
+    /// let result: Vec<usize> = set.iter(target_index).copied().collect();
     ///
-    /// assert_eq!(set.front(list_index), Some(&300));
+    /// assert_eq!(result, vec![8, 7, 5, 4, 3, 0]);
     /// ```
-    pub fn push_front(&mut self, list_index: ListIndex, item: T) {
-        let new_node = Node::new(list_index, item);
-        let new_node_index = NodeIndex::new(self.nodes.len());
-        self.nodes.push(new_node);
-        if self.get_list_unchecked(list_index).is_empty() {
-            let list = self.get_list_mut_unchecked(list_index);
-            list.front = new_node_index;
-            list.back = new_node_index;
-        } else {
-            let front = self.get_list_unchecked(list_index).front;
-            self.link_list_node(
-                new_node_index, 
-                NodeIndex::end(),
-                front
-            );
-            self.get_list_mut_unchecked(list_index).front = new_node_index;
-        }
-
-        self.get_list_mut_unchecked(list_index).length += 1;
-    }
-
-    /// Unlink a node from a linked list.
-    fn unlink_list_node(&mut self, node_index: NodeIndex) {
-        let previous_index = {
-            let node = self.get_node_unchecked(node_index);
-            node.previous
-        };
-        let next_index = {
-            let node = self.get_node_unchecked(node_index);
-            node.next
-        };
-
-        if previous_index != NodeIndex::end() {
-            let previous_node = self.get_node_mut_unchecked(previous_index);
-            previous_node.next = next_index;
-        }
-        
-        if next_index != NodeIndex::end() {
-            let next_node = self.get_node_mut_unchecked(next_index);
-            next_node.previous = previous_index;
-        }
-
-        let list_index = {
-            let node = self.get_node_unchecked(node_index);
-            node.list
-        };
-        let list = self.get_list_mut_unchecked(list_index);
-
-        if list.front == node_index {
-            list.front = next_index;
-        }
-
-        if list.back == node_index {
-            list.back = previous_index;
-        }
+    pub fn merge_sorted_by<F>(&mut self, target_index: ListIndex<Ix>, source_index: ListIndex<Ix>, mut compare: F)
+    where
+        F: FnMut(&T, &T) -> Ordering
+    {
+        assert_ne!(source_index, target_index, "cannot merge a linked list into itself");
+        let mut next_index = self.get_list_unchecked(target_index).front;
+        loop {
+            let current_index = self.get_list_unchecked(source_index).front;
+            if current_index == NodeIndex::end() {
+                break;
+            }
 
-        list.length -= 1;
-    }
+            while next_index != NodeIndex::end() {
+                let next_item = self.get_item_unchecked(next_index);
+                let current_item = self.get_item_unchecked(current_index);
+                if compare(next_item, current_item) == Ordering::Greater {
+                    break;
+                }
 
-    /// Relink a list node after moving it to a different entry in the 
-    /// underlying storage.
-    fn relink_list_node(
-        &mut self, 
-        old_node_index: NodeIndex, 
-        new_node_index: NodeIndex
-    ) {
-        if old_node_index != new_node_index {
-            let previous_index = {
-                let node = self.get_node_unchecked(old_node_index);
-                node.previous
-            };
-            let next_index = {
-                let node = self.get_node_unchecked(old_node_index);
-                node.next
-            };
-        
-            if previous_index != NodeIndex::end() {
-                let previous_node = self.get_node_mut_unchecked(previous_index);
-                previous_node.next = new_node_index;
+                next_index = self.get_node_unchecked(next_index).next();
             }
 
-            if next_index != NodeIndex::end() {
-                let next_node = self.get_node_mut_unchecked(next_index);
-                next_node.previous = new_node_index;
+            // Detach the node from the source list before linking it into the
+            // target list, so that both lists stay valid if a later 
+            // comparison panics.
+            let source_next_index = self.get_node_unchecked(current_index).next();
+            let source = self.get_list_mut_unchecked(source_index);
+            source.front = source_next_index;
+            source.length -= 1;
+            if source_next_index != NodeIndex::end() {
+                self.get_node_mut_unchecked(source_next_index).previous = NodeIndex::end();
+            } else {
+                source.back = NodeIndex::end();
             }
 
-            // Check front and back of the list of the node index being 
-            // moved, old_node_index.
-            let list_index = {
-                let node = self.get_node_unchecked(old_node_index);
-                node.list
+            let previous_index = if next_index != NodeIndex::end() {
+                self.get_node_unchecked(next_index).previous()
+            } else {
+                self.get_list_unchecked(target_index).back
             };
-            let list = self.get_list_mut_unchecked(list_index);
-        
-            if list.front == old_node_index {
-                list.front = new_node_index;
-            }
-        
-            if list.back == old_node_index {
-                list.back = new_node_index;
-            } 
-        }
-    }
+            *self.get_node_list_mut_unchecked(current_index) = target_index.index;
+            self.link_list_node(current_index, previous_index, next_index);
 
-    /// Remove a linked list node from the set.
-    ///
-    /// The function unlinks the node with the input node index from its linked 
-    /// list. It may internally modify the layout of other linked lists in the 
-    /// process of removing the node to keep the nodes in the underlying storage 
-    /// packed.
-    ///
-    /// # Assumptions
-    /// * The list node index exists in the set.
-    /// 
-    /// # Panics
-    ///
-    /// This function panics if `node_to_be_removed_index` is out of bounds.
-    fn remove_list_node(&mut self, node_to_be_removed_index: NodeIndex) -> T {
-        let node_to_be_moved_index = NodeIndex::new(self.nodes.len() - 1);
-        self.unlink_list_node(node_to_be_removed_index);
-        self.relink_list_node(node_to_be_moved_index, node_to_be_removed_index);
+            let target = self.get_list_mut_unchecked(target_index);
+            if previous_index == NodeIndex::end() {
+                target.front = current_index;
+            }
 
-        let removed = self.nodes.swap_remove(node_to_be_removed_index.0);
+            if next_index == NodeIndex::end() {
+                target.back = current_index;
+            }
 
-        removed.item
+            target.length += 1;
+        }
     }
 
-    /// Remove and return an item at a specific position in a linked list.
+    /// Remove the consecutive repeated elements of a sorted linked list, 
+    /// keeping the first element of each run.
     ///
-    /// Returns `None` if the list is empty, or `at` falls outside the length 
-    /// of the list.
+    /// If the list is sorted, this removes every duplicate element.
     ///
     /// # Example
     ///
@@ -1326,240 +5928,350 @@ impl<T> LinkedListSet<T> {
     /// #
     /// let mut set = LinkedListSet::new();
     /// let list_index = set.new_list();
-    /// set.extend(list_index, vec![0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// set.extend(list_index, vec![1, 1, 2, 3, 3, 3, 4]);
+    /// set.dedup_sorted(list_index);
+    /// let result: Vec<usize> = set.iter(list_index).copied().collect();
     ///
-    /// assert!(set.remove(list_index, 5).is_some());
-    /// assert!(set.remove(list_index, 11).is_none());
+    /// assert_eq!(result, vec![1, 2, 3, 4]);
     /// ```
-    pub fn remove(&mut self, list_index: ListIndex, at: usize) -> Option<(T, usize)> {
-        let mut current_index = self.get_list_unchecked(list_index).front;
-        let mut i = 0;
-        while current_index != NodeIndex::end() {
-            let current_node = self.get_node_unchecked(current_index);
-            if i == at {
-                let res = self.remove_list_node(current_index);
-
-                return Some((res, i));
-            } else {
-                current_index = current_node.next;
-                i += 1;
-            }
-        }
-
-        None
+    pub fn dedup_sorted(&mut self, list_index: ListIndex<Ix>)
+    where
+        T: PartialEq
+    {
+        self.dedup(list_index);
     }
 
-    /// Remove and return the first instance of an item from a linked list
-    /// in a linked list set.
+    /// Remove the consecutive repeated elements of a linked list, keeping the
+    /// first element of each run.
     ///
-    /// Returns `None` if the linked list is empty, or `item` is not present 
-    /// in the list.
+    /// The list is traversed once through the node links.
     ///
     /// # Example
     ///
     /// ```
     /// # use list_set::{
-    /// #     LinkedListSet, 
+    /// #     LinkedListSet,
     /// # };
     /// #
     /// let mut set = LinkedListSet::new();
-    /// let list_index0 = set.new_list();
-    /// // Insert a collection with exactly one instance of `1`.
-    /// set.extend(list_index0, vec![0, 0, 0, 1, 0, 0, 0]);
-    /// 
-    /// assert!(set.contains(list_index0, &1));
-    /// assert!(set.remove_item(list_index0, &1).is_some());
-    /// // The set no longer contains the removed item.
-    /// assert!(!set.contains(list_index0, &1));
+    /// let list_index = set.new_list();
+    /// set.extend(list_index, vec![1, 1, 2, 1, 1, 3, 3]);
+    /// set.dedup(list_index);
+    /// let result: Vec<usize> = set.iter(list_index).copied().collect();
     ///
-    /// let list_index1 = set.new_list();
-    /// // Insert a collection with more than one instance of `1`.
-    /// set.extend(list_index1, vec![0, 0, 0, 1, 0, 1, 0]);
+    /// assert_eq!(result, vec![1, 2, 1, 3]);
+    /// ```
+    pub fn dedup(&mut self, list_index: ListIndex<Ix>)
+    where
+        T: PartialEq
+    {
+        self.dedup_by(list_index, |a, b| a == b);
+    }
+
+    /// Remove the consecutive elements of a linked list that the function 
+    /// `same_bucket` considers equal, keeping the first element of each run.
     ///
-    /// assert!(set.contains(list_index1, &1));
-    /// assert!(set.remove_item(list_index1, &1).is_some());
-    /// // The set still contains an instance of the removed item.
-    /// assert!(set.contains(list_index1, &1));
+    /// As with `Vec::dedup_by`, `same_bucket` receives each element followed
+    /// by the element it is compared against, which is the last element kept
+    /// before it. The element is removed if `same_bucket` returns `true`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.extend(list_index, vec!["foo", "FOO", "bar", "Bar", "baz"]);
+    /// set.dedup_by(list_index, |a, b| a.eq_ignore_ascii_case(b));
+    /// let result: Vec<&str> = set.iter(list_index).copied().collect();
+    ///
+    /// assert_eq!(result, vec!["foo", "bar", "baz"]);
     /// ```
-    pub fn remove_item(&mut self, list_index: ListIndex, item: &T) -> Option<(T, usize)> 
+    pub fn dedup_by<F>(&mut self, list_index: ListIndex<Ix>, mut same_bucket: F)
     where
-        T: PartialEq<T>
+        F: FnMut(&mut T, &mut T) -> bool
     {
         let mut current_index = self.get_list_unchecked(list_index).front;
-        let mut i = 0;
         while current_index != NodeIndex::end() {
-            let current_node = self.get_node_unchecked(current_index);
-            if current_node.item() == item {
-                let res = self.remove_list_node(current_index);
+            let next_index = self.get_node_unchecked(current_index).next();
+            if next_index == NodeIndex::end() {
+                break;
+            }
 
-                return Some((res, i));
+            let is_duplicate = {
+                let (next_item, current_item) = self.get_item_pair_mut_unchecked(next_index, current_index);
+                same_bucket(next_item, current_item)
+            };
+            if is_duplicate {
+                self.remove_list_node_tracking(next_index, &mut current_index);
             } else {
-                current_index = current_node.next();
-                i += 1;
+                current_index = next_index;
             }
         }
-
-        None
     }
 
-    /// Remove and return the first element from a linked list in a linked
-    /// list set.
-    ///
-    /// Returns `None` if the linked list is empty.
+    /// Remove the consecutive elements of a linked list that map to the same
+    /// key, keeping the first element of each run.
     ///
     /// # Example
     ///
     /// ```
     /// # use list_set::{
-    /// #     LinkedListSet, 
+    /// #     LinkedListSet,
     /// # };
     /// #
     /// let mut set = LinkedListSet::new();
     /// let list_index = set.new_list();
-    /// set.push_back(list_index, 1);
-    /// set.push_back(list_index, 2);
-    /// set.push_back(list_index, 3);
+    /// set.extend(list_index, vec![10, 11, 20, 21, 22, 30, 11]);
+    /// set.dedup_by_key(list_index, |item| *item / 10);
+    /// let result: Vec<usize> = set.iter(list_index).copied().collect();
     ///
-    /// let result = set.pop_front(list_index);
-    /// assert_eq!(result, Some(1));
+    /// assert_eq!(result, vec![10, 20, 30, 11]);
     /// ```
-    pub fn pop_front(&mut self, list_index: ListIndex) -> Option<T> {
-        let front_node_index = self.get_list_unchecked(list_index).front;
-        if front_node_index != NodeIndex::end() {
-            let item = self.remove_list_node(front_node_index);
-
-            return Some(item);
-        }
-
-        None
+    pub fn dedup_by_key<K, F>(&mut self, list_index: ListIndex<Ix>, mut key: F)
+    where
+        F: FnMut(&mut T) -> K,
+        K: PartialEq
+    {
+        self.dedup_by(list_index, |a, b| key(a) == key(b));
     }
 
-    /// Remove and return the last element from a linked list in a linked
-    /// list set.
+    /// Remove every repeated element of a linked list, including elements 
+    /// that are not adjacent, keeping the first occurrence of each element.
     ///
-    /// Returns `None` if the linked list is empty.
+    /// The elements are hashed to find the duplicates in one pass, and the
+    /// remaining elements keep their order.
     ///
     /// # Example
     ///
     /// ```
     /// # use list_set::{
-    /// #     LinkedListSet, 
+    /// #     LinkedListSet,
     /// # };
     /// #
     /// let mut set = LinkedListSet::new();
     /// let list_index = set.new_list();
-    /// set.push_back(list_index, 1);
-    /// set.push_back(list_index, 2);
-    /// set.push_back(list_index, 3);
+    /// set.extend(list_index, vec![3, 1, 3, 2, 1, 4]);
+    /// set.dedup_all(list_index);
+    /// let result: Vec<usize> = set.iter(list_index).copied().collect();
     ///
-    /// let result = set.pop_back(list_index);
-    /// assert_eq!(result, Some(3));
+    /// assert_eq!(result, vec![3, 1, 2, 4]);
     /// ```
-    pub fn pop_back(&mut self, list_index: ListIndex) -> Option<T> {
-        let back_node_index = self.get_list_unchecked(list_index).back;
-        if back_node_index != NodeIndex::end() {
-            let item = self.remove_list_node(back_node_index);
+    pub fn dedup_all(&mut self, list_index: ListIndex<Ix>)
+    where
+        T: Hash + Eq
+    {
+        let mut duplicate_indices = {
+            let mut seen = HashSet::with_capacity(self.len(list_index));
+            let mut duplicate_indices = Vec::new();
+            let mut current_index = self.get_list_unchecked(list_index).front;
+            while current_index != NodeIndex::end() {
+                if !seen.insert(self.get_item_unchecked(current_index)) {
+                    duplicate_indices.push(current_index);
+                }
 
-            return Some(item);
-        }
+                current_index = self.get_node_unchecked(current_index).next();
+            }
 
-        None
+            duplicate_indices
+        };
+
+        // Removing a node relocates the last node in the underlying storage.
+        // Removing the nodes from the back of the storage first guarantees 
+        // that none of the relocated nodes is still waiting to be removed.
+        duplicate_indices.sort_unstable_by_key(|node_index| Reverse(node_index.index()));
+        for node_index in duplicate_indices {
+            self.remove_list_node(node_index);
+        }
     }
 
-    /// Remove all elements from a linked list in the set.
+    /// Reverse the order of the elements of a linked list.
+    ///
+    /// The list is reversed by swapping the links of each node, so no items 
+    /// move in the underlying storage, and the node handles remain valid.
     ///
     /// # Example
     ///
     /// ```
     /// # use list_set::{
-    /// #     LinkedListSet,  
+    /// #     LinkedListSet,
     /// # };
     /// #
     /// let mut set = LinkedListSet::new();
     /// let list_index = set.new_list();
-    /// set.extend(
-    ///     list_index, 
-    ///     vec![String::from("spam"), String::from("eggs")
-    /// ]);
-    ///
-    /// assert!(!set.list_is_empty(list_index));
-    ///
-    /// set.clear(list_index);
+    /// set.extend(list_index, vec![1, 2, 3, 4]);
+    /// set.reverse(list_index);
+    /// let result: Vec<usize> = set.iter(list_index).copied().collect();
     ///
-    /// assert!(set.list_is_empty(list_index));
+    /// assert_eq!(result, vec![4, 3, 2, 1]);
     /// ```
-    pub fn clear(&mut self, list_index: ListIndex) {
-        while !self.list_is_empty(list_index) {
-            self.pop_front(list_index);
+    pub fn reverse(&mut self, list_index: ListIndex<Ix>) {
+        let mut current_index = self.get_list_unchecked(list_index).front;
+        while current_index != NodeIndex::end() {
+            let current_node = self.get_node_mut_unchecked(current_index);
+            mem::swap(&mut current_node.previous, &mut current_node.next);
+            current_index = current_node.previous();
         }
+
+        let list = self.get_list_mut_unchecked(list_index);
+        mem::swap(&mut list.front, &mut list.back);
     }
 
-    /// Remove a linked list and all its data from the set.
+    /// Rotate a linked list `k` places to the left.
     ///
-    /// Returns `true` if the list removed exists in the 
-    /// set prior to calling `remove_list`, and `false` otherwise. The 
-    /// `remove_list` function preserves the indices of all the other lists 
-    /// in the set. If `remove_list` returns false, no lists were affected.
+    /// After the rotation, the element at position `k` becomes the front of 
+    /// the list, and the elements before it move to the back. The list is 
+    /// rotated by relinking its ends, so no items move in the underlying 
+    /// storage, and the new front is found from whichever end of the list is
+    /// closer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is greater than the length of the list.
     ///
     /// # Example
     ///
     /// ```
     /// # use list_set::{
     /// #     LinkedListSet,
-    /// #     ListIndex, 
-    /// # }; 
+    /// # };
     /// #
     /// let mut set = LinkedListSet::new();
-    /// let list_indices = [set.new_list(), set.new_list(), set.new_list()];
-    /// for (i, &list_index_i) in list_indices.iter().enumerate() {
-    ///     set.extend(list_index_i, vec![i; 5]);  
-    /// }
-    ///
-    /// assert!(list_indices.iter().all(|l| set.contains_list(*l)));
-    /// // Remove the list pointed to by `list_indices[0]`.
-    /// set.remove_list(list_indices[0]);
+    /// let list_index = set.new_list();
+    /// set.extend(list_index, vec![1, 2, 3, 4, 5]);
+    /// set.rotate_left(list_index, 2);
+    /// let result: Vec<usize> = set.iter(list_index).copied().collect();
     ///
-    /// assert!(!set.contains_list(list_indices[0]));
-    /// assert!(list_indices[1..].iter().all(|l| set.contains_list(*l)));
+    /// assert_eq!(result, vec![3, 4, 5, 1, 2]);
     /// ```
-    pub fn remove_list(&mut self, list_index: ListIndex) -> bool {
-        if self.contains_list(list_index) {
-            self.clear(list_index);
-            self.lists.remove(&list_index);
-
-            true
-        } else {
-            false
+    pub fn rotate_left(&mut self, list_index: ListIndex<Ix>, k: usize) {
+        let length = self.len(list_index);
+        assert!(k <= length, "cannot rotate a linked list by more than its length");
+        if k == 0 || k == length {
+            return;
         }
+
+        let new_front_index = self.find_list_node(list_index, k);
+        let new_back_index = self.get_node_unchecked(new_front_index).previous();
+        let (old_front_index, old_back_index) = {
+            let list = self.get_list_unchecked(list_index);
+            (list.front, list.back)
+        };
+
+        self.get_node_mut_unchecked(old_back_index).next = old_front_index;
+        self.get_node_mut_unchecked(old_front_index).previous = old_back_index;
+        self.get_node_mut_unchecked(new_front_index).previous = NodeIndex::end();
+        self.get_node_mut_unchecked(new_back_index).next = NodeIndex::end();
+
+        let list = self.get_list_mut_unchecked(list_index);
+        list.front = new_front_index;
+        list.back = new_back_index;
     }
 
-    /// Extend a linked list with an iterable collection of items.
+    /// Rotate a linked list `k` places to the right.
+    ///
+    /// After the rotation, the last `k` elements of the list move to the 
+    /// front. The list is rotated by relinking its ends, so no items move in
+    /// the underlying storage.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is greater than the length of the list.
     ///
     /// # Example
-    /// 
+    ///
     /// ```
     /// # use list_set::{
     /// #     LinkedListSet,
-    /// #     
     /// # };
     /// #
     /// let mut set = LinkedListSet::new();
     /// let list_index = set.new_list();
-    /// let expected = vec![0, 1, 2, 3, 4, 5];
-    /// 
-    /// set.extend(list_index, expected.iter().copied());
-    /// 
+    /// set.extend(list_index, vec![1, 2, 3, 4, 5]);
+    /// set.rotate_right(list_index, 2);
     /// let result: Vec<usize> = set.iter(list_index).copied().collect();
     ///
-    /// assert_eq!(result, expected);
+    /// assert_eq!(result, vec![4, 5, 1, 2, 3]);
     /// ```
-    pub fn extend<I>(&mut self, list_index: ListIndex, items: I)
-    where
-        I: IntoIterator<Item = T>
-    {
-        for item in items.into_iter() {
-            self.push_back(list_index, item);
+    pub fn rotate_right(&mut self, list_index: ListIndex<Ix>, k: usize) {
+        let length = self.len(list_index);
+        assert!(k <= length, "cannot rotate a linked list by more than its length");
+        self.rotate_left(list_index, length - k);
+    }
+}
+
+impl<T, Ix: IndexType> LinkedListSet<T, Ix, SoaStorage<T, Ix>> {
+    /// Returns the elements of a linked list as at most two slices, in list 
+    /// order, or `None` if the list is split into more pieces than that in 
+    /// the underlying storage.
+    ///
+    /// The structure-of-arrays layout stores the items apart from the links 
+    /// between nodes, so a run of list nodes that occupy consecutive positions in the 
+    /// underlying storage is also a slice of items. This is the case for a 
+    /// list built with a single `extend`, and for every list after a 
+    /// `compact`. The contents of the list are the first slice followed by 
+    /// the second slice, and the second slice is empty when the whole list 
+    /// is one run. Finding the runs walks the list once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `list_index` does not point to a linked list in the set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     SoaLinkedListSet,
+    /// # };
+    /// #
+    /// let mut set: SoaLinkedListSet<usize> = SoaLinkedListSet::default();
+    /// let list_indices = [set.new_list(), set.new_list()];
+    /// set.extend(list_indices[0], vec![1, 2, 3]);
+    ///
+    /// assert_eq!(set.try_as_slices(list_indices[0]), Some((&[1, 2, 3][..], &[][..])));
+    ///
+    /// set.push_front(list_indices[0], 0);
+    ///
+    /// assert_eq!(set.try_as_slices(list_indices[0]), Some((&[0][..], &[1, 2, 3][..])));
+    ///
+    /// set.push_back(list_indices[1], 10);
+    /// set.push_back(list_indices[0], 4);
+    ///
+    /// assert_eq!(set.try_as_slices(list_indices[0]), None);
+    ///
+    /// set.compact();
+    ///
+    /// assert_eq!(set.try_as_slices(list_indices[0]), Some((&[0, 1, 2, 3, 4][..], &[][..])));
+    /// ```
+    pub fn try_as_slices(&self, list_index: ListIndex<Ix>) -> Option<(&[T], &[T])> {
+        let mut runs = [(0, 0); 2];
+        let mut run_count = 0;
+        let mut previous_index = NodeIndex::end();
+        let mut current_index = self.get_list_unchecked(list_index).front;
+        while current_index != NodeIndex::end() {
+            if previous_index == NodeIndex::end() || current_index.index() != previous_index.index() + 1 {
+                if run_count == runs.len() {
+                    return None;
+                }
+
+                runs[run_count] = (current_index.index(), 0);
+                run_count += 1;
+            }
+
+            runs[run_count - 1].1 += 1;
+            previous_index = current_index;
+            current_index = self.get_node_unchecked(current_index).next();
         }
+
+        let [(first_start, first_length), (second_start, second_length)] = runs;
+
+        Some((
+            &self.nodes.items()[first_start..(first_start + first_length)],
+            &self.nodes.items()[second_start..(second_start + second_length)],
+        ))
     }
 }
 
@@ -1590,9 +6302,8 @@ mod tests {
             let mut current_index = set.get_list_unchecked(list_index).front;
             let expected = list_index;
             while current_index != NodeIndex::end() {
-                let current_node = set.get_node_unchecked(current_index);
-                let result = current_node.list;
-                current_index = current_node.next();
+                let result = set.get_node_list_unchecked(current_index);
+                current_index = set.get_node_unchecked(current_index).next();
 
                 assert_eq!(result, expected);
             }
@@ -1603,136 +6314,160 @@ mod tests {
 #[cfg(test)]
 mod iter_mut_tests {
     use super::*;
+    use std::collections::{
+        HashMap,
+    };
 
 
     struct Test {
         set: LinkedListSet<usize>,
-        expected: FnvHashMap<ListIndex, Vec<usize>>,
+        expected: HashMap<ListIndex, Vec<usize>>,
     }
 
     fn linked_list_set() -> LinkedListSet<usize> {
         // We hand construct the lists to ensure the nodes in a given list are not 
         // adjacent to each other in the underlying storage.
-        let mut lists = FnvHashMap::default();
-        lists.insert(ListIndex::new(0), LinkedList {
-            front: NodeIndex::new(0),
-            back: NodeIndex::new(9),
-            length: 4,
-            _marker: PhantomData,
-        });
-        lists.insert(ListIndex::new(1), LinkedList {
-            front: NodeIndex::new(1),
-            back: NodeIndex::new(10),
-            length: 4,
-            _marker: PhantomData,
-        });
-        lists.insert(ListIndex::new(2), LinkedList {
-            front: NodeIndex::new(2),
-            back: NodeIndex::new(13),
-            length: 6,
-            _marker: PhantomData,
-        });
+        let mut lists = ListSlots::new();
+        let list_indices = [
+            lists.insert(LinkedList {
+                front: NodeIndex::new(0),
+                back: NodeIndex::new(9),
+                length: 4,
+                _marker: PhantomData,
+            }),
+            lists.insert(LinkedList {
+                front: NodeIndex::new(1),
+                back: NodeIndex::new(10),
+                length: 4,
+                _marker: PhantomData,
+            }),
+            lists.insert(LinkedList {
+                front: NodeIndex::new(2),
+                back: NodeIndex::new(13),
+                length: 6,
+                _marker: PhantomData,
+            }),
+        ];
         let nodes = vec![
             Node {
-                item: 10,
-                list: ListIndex::new(0),
                 previous: NodeIndex::end(),
                 next: NodeIndex::new(3),
+                handle: 0,
             },
             Node {
-                item: 20,
-                list: ListIndex::new(1),
                 previous: NodeIndex::end(),
                 next: NodeIndex::new(4),
+                handle: 1,
             },
             Node {
-                item: 30,
-                list: ListIndex::new(2),
                 previous: NodeIndex::end(),
                 next: NodeIndex::new(5),
+                handle: 2,
             },
             Node {
-                item: 11,
-                list: ListIndex::new(0),
                 previous: NodeIndex::new(0),
                 next: NodeIndex::new(6),
+                handle: 3,
             },
             Node {
-                item: 21,
-                list: ListIndex::new(1),
                 previous: NodeIndex::new(1),
                 next: NodeIndex::new(7),
+                handle: 4,
             },
             Node {
-                item: 31,
-                list: ListIndex::new(2),
                 previous: NodeIndex::new(2),
                 next: NodeIndex::new(8),
+                handle: 5,
             },
             Node {
-                item: 12,
-                list: ListIndex::new(0),
                 previous: NodeIndex::new(3),
                 next: NodeIndex::new(9),
+                handle: 6,
             },
             Node {
-                item: 22,
-                list: ListIndex::new(1),
                 previous: NodeIndex::new(4),
                 next: NodeIndex::new(10),
+                handle: 7,
             },
             Node {
-                item: 32,
-                list: ListIndex::new(2),
                 previous: NodeIndex::new(5),
                 next: NodeIndex::new(11),
+                handle: 8,
             },
             Node {
-                item: 13,
-                list: ListIndex::new(0),
                 previous: NodeIndex::new(6),
                 next: NodeIndex::end(),
+                handle: 9,
             },
             Node {
-                item: 23,
-                list: ListIndex::new(1),
                 previous: NodeIndex::new(7),
                 next: NodeIndex::end(),
+                handle: 10,
             },
             Node {
-                item: 33,
-                list: ListIndex::new(2),
                 previous: NodeIndex::new(8),
                 next: NodeIndex::new(12),
+                handle: 11,
             },
             Node {
-                item: 34,
-                list: ListIndex::new(2),
                 previous: NodeIndex::new(11),
                 next: NodeIndex::new(13),
+                handle: 12,
             },
             Node {
-                item: 35,
-                list: ListIndex::new(2),
                 previous: NodeIndex::new(12),
                 next: NodeIndex::end(),
+                handle: 13,
             },
         ];
-        let alloc = ListIndexAllocator::new();
+        let node_lists = vec![
+            list_indices[0],
+            list_indices[1],
+            list_indices[2],
+            list_indices[0],
+            list_indices[1],
+            list_indices[2],
+            list_indices[0],
+            list_indices[1],
+            list_indices[2],
+            list_indices[0],
+            list_indices[1],
+            list_indices[2],
+            list_indices[2],
+            list_indices[2],
+        ];
+        let items = vec![10, 20, 30, 11, 21, 31, 12, 22, 32, 13, 23, 33, 34, 35];
+        let mut handles = NodeHandleAllocator::new();
+        for node_index in 0..nodes.len() {
+            handles.new_handle(NodeIndex::new(node_index), lists.owner);
+        }
+        let mut storage = AosStorage::default();
+        for ((node, list_index), item) in nodes.into_iter().zip(node_lists).zip(items) {
+            storage.push(node, list_index.index, item);
+        }
         
         LinkedListSet {
-            alloc: alloc,
+            handles: handles,
             lists: lists,
-            nodes: nodes,
+            nodes: storage,
+            defragment: DefragmentState::default(),
         }
     }
 
+    fn sorted_list_indices(set: &LinkedListSet<usize>) -> Vec<ListIndex> {
+        let mut list_indices: Vec<ListIndex> = set.list_indices().collect();
+        list_indices.sort();
+
+        list_indices
+    }
+
     fn test() -> Test {
         let set = linked_list_set();
-        let mut expected = FnvHashMap::default();
-        expected.insert(ListIndex::new(0), vec![10, 11, 12, 13]);
-        expected.insert(ListIndex::new(1), vec![20, 21, 22, 23]);
-        expected.insert(ListIndex::new(2), vec![30, 31, 32, 33, 34, 35]);
+        let list_indices = sorted_list_indices(&set);
+        let mut expected = HashMap::default();
+        expected.insert(list_indices[0], vec![10, 11, 12, 13]);
+        expected.insert(list_indices[1], vec![20, 21, 22, 23]);
+        expected.insert(list_indices[2], vec![30, 31, 32, 33, 34, 35]);
 
         Test {
             set: set,
@@ -1742,10 +6477,11 @@ mod iter_mut_tests {
 
     fn test_rev() -> Test {
         let set = linked_list_set();
-        let mut expected = FnvHashMap::default();
-        expected.insert(ListIndex::new(0), vec![13, 12, 11, 10]);
-        expected.insert(ListIndex::new(1), vec![23, 22, 21, 20]);
-        expected.insert(ListIndex::new(2), vec![35, 34, 33, 32, 31, 30]);
+        let list_indices = sorted_list_indices(&set);
+        let mut expected = HashMap::default();
+        expected.insert(list_indices[0], vec![13, 12, 11, 10]);
+        expected.insert(list_indices[1], vec![23, 22, 21, 20]);
+        expected.insert(list_indices[2], vec![35, 34, 33, 32, 31, 30]);
 
         Test {
             set: set,
@@ -1820,6 +6556,49 @@ mod iter_mut_tests {
             assert_eq!(result.len(), expected.len());
         }
     }
+
+    /// A mutable iterator should panic instead of yielding a node that 
+    /// belongs to a different list, so that mutable iterators over distinct 
+    /// lists never alias each other.
+    #[test]
+    #[should_panic(expected = "linked list node belongs to a different list")]
+    fn test_mutable_iterator_panics_on_node_from_another_list() {
+        let mut set = linked_list_set();
+        let list_indices = sorted_list_indices(&set);
+        *set.get_node_list_mut_unchecked(NodeIndex::new(6)) = list_indices[1].index;
+
+        for item in set.iter_mut(list_indices[0]) {
+            *item += 1;
+        }
+    }
+
+    /// A mutable iterator should panic if the chain of a list ends before
+    /// the length of the list is reached.
+    #[test]
+    #[should_panic(expected = "linked list ended before reaching its length")]
+    fn test_mutable_iterator_panics_on_short_chain() {
+        let mut set = linked_list_set();
+        let list_indices = sorted_list_indices(&set);
+        set.get_list_mut_unchecked(list_indices[0]).length += 1;
+
+        for item in set.iter_mut(list_indices[0]) {
+            *item += 1;
+        }
+    }
+
+    /// A mutable iterator in reverse should panic if the chain of a list 
+    /// ends before the length of the list is reached.
+    #[test]
+    #[should_panic(expected = "linked list ended before reaching its length")]
+    fn test_mutable_iterator_reverse_panics_on_short_chain() {
+        let mut set = linked_list_set();
+        let list_indices = sorted_list_indices(&set);
+        set.get_list_mut_unchecked(list_indices[1]).length += 1;
+
+        for item in set.iter_mut(list_indices[1]).rev() {
+            *item += 1;
+        }
+    }
 }
 
 
@@ -1895,3 +6674,29 @@ mod unlink_tests {
     }
 }
 
+
+
+#[cfg(test)]
+mod layout_tests {
+    use super::*;
+    use std::mem;
+
+
+    /// Every node of a set costs its links, the position of its linked list,
+    /// and its handle slot, all stored in the index type of the set.
+    #[test]
+    fn test_node_overhead_u32() {
+        assert_eq!(mem::size_of::<Node<u32>>(), 12);
+        assert_eq!(mem::size_of::<storage::AosNode<(), u32>>(), 16);
+        assert_eq!(mem::size_of::<HandleSlot<u32>>(), 8);
+        assert_eq!(mem::size_of::<ListIndex<u32>>(), 12);
+    }
+
+    #[test]
+    fn test_node_overhead_u16() {
+        assert_eq!(mem::size_of::<Node<u16>>(), 6);
+        assert_eq!(mem::size_of::<storage::AosNode<(), u16>>(), 8);
+        assert_eq!(mem::size_of::<HandleSlot<u16>>(), 8);
+        assert_eq!(mem::size_of::<ListIndex<u16>>(), 12);
+    }
+}
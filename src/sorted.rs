@@ -0,0 +1,331 @@
+use crate::{
+    AosStorage,
+    IndexType,
+    LinkedListSet,
+    ListIndex,
+    ListIter,
+    NodeHandle,
+    NodeIndex,
+};
+use crate::storage::{
+    NodeStorage,
+};
+use std::cmp::{
+    Ordering,
+};
+
+
+/// A linked list set whose linked lists are each kept sorted in ascending
+/// order.
+///
+/// Every operation that adds elements to a list places them in sorted
+/// position, and the set gives out no mutable references to its elements, so
+/// the ordering invariant holds after every mutation. In exchange, searching
+/// a list can stop as soon as it passes the position where an item would be.
+#[derive(Debug)]
+pub struct SortedListSet<T: Ord, Ix = u32, S = AosStorage<T, Ix>> {
+    set: LinkedListSet<T, Ix, S>,
+}
+
+impl<T: Ord> SortedListSet<T> {
+    /// Create a new sorted linked list set with the default index type `u32`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     SortedListSet,
+    /// # };
+    /// #
+    /// let set: SortedListSet<usize> = SortedListSet::new();
+    ///
+    /// assert!(set.is_empty());
+    /// ```
+    pub fn new() -> Self {
+        Self {
+            set: LinkedListSet::new(),
+        }
+    }
+}
+
+impl<T: Ord, Ix: IndexType, S: NodeStorage<T, Ix>> SortedListSet<T, Ix, S> {
+    /// Construct a sorted linked list set from a linked list set by sorting
+    /// every linked list in it.
+    ///
+    /// The list indices and node handles of the linked list set remain valid.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     LinkedListSet,
+    /// #     SortedListSet,
+    /// # };
+    /// #
+    /// let mut set = LinkedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.extend(list_index, vec![3, 1, 2]);
+    /// let sorted_set = SortedListSet::from_set(set);
+    /// let result: Vec<usize> = sorted_set.iter(list_index).copied().collect();
+    ///
+    /// assert_eq!(result, vec![1, 2, 3]);
+    /// ```
+    pub fn from_set(mut set: LinkedListSet<T, Ix, S>) -> Self {
+        let list_indices: Vec<ListIndex<Ix>> = set.list_indices().collect();
+        for list_index in list_indices {
+            set.sort(list_index);
+        }
+
+        Self {
+            set: set,
+        }
+    }
+
+    /// Returns a reference to the underlying linked list set.
+    #[inline]
+    pub fn as_set(&self) -> &LinkedListSet<T, Ix, S> {
+        &self.set
+    }
+
+    /// Unwrap the underlying linked list set.
+    #[inline]
+    pub fn into_set(self) -> LinkedListSet<T, Ix, S> {
+        self.set
+    }
+
+    /// Determine whether the set contains no linked lists.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.set.is_empty()
+    }
+
+    /// Create a new empty linked list in the set.
+    pub fn new_list(&mut self) -> ListIndex<Ix> {
+        self.set.new_list()
+    }
+
+    /// Remove a linked list and all its data from the set.
+    ///
+    /// Returns `true` if the list exists in the set prior to calling
+    /// `remove_list`, and `false` otherwise.
+    pub fn remove_list(&mut self, list_index: ListIndex<Ix>) -> bool {
+        self.set.remove_list(list_index)
+    }
+
+    /// Determine whether a list index points to a linked list in the set.
+    pub fn contains_list(&self, list_index: ListIndex<Ix>) -> bool {
+        self.set.contains_list(list_index)
+    }
+
+    /// Returns the length of a linked list in the set.
+    pub fn len(&self, list_index: ListIndex<Ix>) -> usize {
+        self.set.len(list_index)
+    }
+
+    /// Determine whether a linked list in the set is empty.
+    pub fn list_is_empty(&self, list_index: ListIndex<Ix>) -> bool {
+        self.set.list_is_empty(list_index)
+    }
+
+    /// Provide an iterator over a linked list in the set in ascending order.
+    pub fn iter(&self, list_index: ListIndex<Ix>) -> ListIter<'_, T, Ix> {
+        self.set.iter(list_index)
+    }
+
+    /// Provides a reference to the smallest element of a linked list, or
+    /// `None` if the list is empty.
+    pub fn front(&self, list_index: ListIndex<Ix>) -> Option<&T> {
+        self.set.front(list_index)
+    }
+
+    /// Provides a reference to the largest element of a linked list, or
+    /// `None` if the list is empty.
+    pub fn back(&self, list_index: ListIndex<Ix>) -> Option<&T> {
+        self.set.back(list_index)
+    }
+
+    /// Get a reference to the element a node handle points to, or `None` if
+    /// the handle is no longer valid.
+    pub fn get(&self, handle: NodeHandle) -> Option<&T> {
+        self.set.get(handle)
+    }
+
+    /// Insert an item into a linked list in sorted position.
+    ///
+    /// The item goes after every element that compares equal to it. Returns
+    /// a handle to the new element.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     SortedListSet,
+    /// # };
+    /// #
+    /// let mut set = SortedListSet::new();
+    /// let list_index = set.new_list();
+    /// for item in [5, 1, 4, 2, 3] {
+    ///     set.insert(list_index, item);
+    /// }
+    /// let result: Vec<usize> = set.iter(list_index).copied().collect();
+    ///
+    /// assert_eq!(result, vec![1, 2, 3, 4, 5]);
+    /// ```
+    pub fn insert(&mut self, list_index: ListIndex<Ix>, item: T) -> NodeHandle {
+        self.set.insert_sorted(list_index, item)
+    }
+
+    /// Insert every item of an iterable collection into a linked list in
+    /// sorted position.
+    pub fn extend<I>(&mut self, list_index: ListIndex<Ix>, items: I)
+    where
+        I: IntoIterator<Item = T>
+    {
+        for item in items.into_iter() {
+            self.set.insert_sorted(list_index, item);
+        }
+    }
+
+    /// Find the first node in a linked list that does not compare less than
+    /// `item`, or `NodeIndex::end()` if there is none.
+    fn lower_bound(&self, list_index: ListIndex<Ix>, item: &T) -> NodeIndex<Ix> {
+        let mut current_index = self.set.get_list_unchecked(list_index).front;
+        while current_index != NodeIndex::end() {
+            if self.set.get_item_unchecked(current_index) >= item {
+                break;
+            }
+
+            current_index = self.set.get_node_unchecked(current_index).next();
+        }
+
+        current_index
+    }
+
+    /// Determine whether a linked list contains an item.
+    ///
+    /// The search stops at the first element that does not compare less than
+    /// `item`, rather than scanning the rest of the list.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     SortedListSet,
+    /// # };
+    /// #
+    /// let mut set = SortedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.extend(list_index, vec![1, 3, 5]);
+    ///
+    /// assert!(set.contains(list_index, &3));
+    /// assert!(!set.contains(list_index, &4));
+    /// ```
+    pub fn contains(&self, list_index: ListIndex<Ix>, item: &T) -> bool {
+        let node_index = self.lower_bound(list_index, item);
+
+        node_index != NodeIndex::end()
+            && self.set.get_item_unchecked(node_index).cmp(item) == Ordering::Equal
+    }
+
+    /// Remove and return the first instance of an item from a linked list.
+    ///
+    /// Returns `None` if `item` is not present in the list. The search stops
+    /// at the first element that does not compare less than `item`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     SortedListSet,
+    /// # };
+    /// #
+    /// let mut set = SortedListSet::new();
+    /// let list_index = set.new_list();
+    /// set.extend(list_index, vec![1, 3, 5]);
+    ///
+    /// assert_eq!(set.remove_item(list_index, &3), Some(3));
+    /// assert_eq!(set.remove_item(list_index, &3), None);
+    /// ```
+    pub fn remove_item(&mut self, list_index: ListIndex<Ix>, item: &T) -> Option<T> {
+        let node_index = self.lower_bound(list_index, item);
+        if node_index != NodeIndex::end()
+            && self.set.get_item_unchecked(node_index).cmp(item) == Ordering::Equal
+        {
+            Some(self.set.remove_list_node(node_index))
+        } else {
+            None
+        }
+    }
+
+    /// Remove and return the element a node handle points to, or `None` if
+    /// the handle is no longer valid.
+    pub fn remove_node(&mut self, handle: NodeHandle) -> Option<T> {
+        self.set.remove_node(handle)
+    }
+
+    /// Remove and return the smallest element of a linked list, or `None` if
+    /// the list is empty.
+    pub fn pop_front(&mut self, list_index: ListIndex<Ix>) -> Option<T> {
+        self.set.pop_front(list_index)
+    }
+
+    /// Remove and return the largest element of a linked list, or `None` if
+    /// the list is empty.
+    pub fn pop_back(&mut self, list_index: ListIndex<Ix>) -> Option<T> {
+        self.set.pop_back(list_index)
+    }
+
+    /// Remove all elements from a linked list in the set.
+    pub fn clear(&mut self, list_index: ListIndex<Ix>) {
+        self.set.clear(list_index)
+    }
+
+    /// Merge the linked list `source_index` into the linked list
+    /// `target_index` in linear time, leaving the source list empty.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `source_index` and `target_index` are the same list, or
+    /// either of them does not point to a linked list in the set.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use list_set::{
+    /// #     SortedListSet,
+    /// # };
+    /// #
+    /// let mut set = SortedListSet::new();
+    /// let target_index = set.new_list();
+    /// let source_index = set.new_list();
+    /// set.extend(target_index, vec![1, 4]);
+    /// set.extend(source_index, vec![2, 3]);
+    /// set.merge(target_index, source_index);
+    /// let result: Vec<usize> = set.iter(target_index).copied().collect();
+    ///
+    /// assert_eq!(result, vec![1, 2, 3, 4]);
+    /// ```
+    pub fn merge(&mut self, target_index: ListIndex<Ix>, source_index: ListIndex<Ix>) {
+        self.set.merge_sorted(target_index, source_index);
+    }
+
+    /// Remove every duplicate element from a linked list.
+    pub fn dedup(&mut self, list_index: ListIndex<Ix>) {
+        self.set.dedup_sorted(list_index);
+    }
+}
+
+impl<T: Ord, Ix: IndexType, S: NodeStorage<T, Ix>> Default for SortedListSet<T, Ix, S> {
+    fn default() -> Self {
+        Self {
+            set: LinkedListSet::default(),
+        }
+    }
+}
+
+impl<T: Ord, Ix: IndexType, S: NodeStorage<T, Ix>> From<LinkedListSet<T, Ix, S>> for SortedListSet<T, Ix, S> {
+    fn from(set: LinkedListSet<T, Ix, S>) -> Self {
+        Self::from_set(set)
+    }
+}
+
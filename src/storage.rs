@@ -0,0 +1,558 @@
+use crate::{
+    IndexType,
+    Node,
+};
+use std::collections::{
+    TryReserveError,
+};
+use std::mem;
+
+
+/// A pointer to a sequence of values that lie a fixed number of bytes apart
+/// in memory.
+///
+/// The elements of a vector form a strided sequence, and so does one field
+/// of every element of a vector of structures.
+#[derive(Debug)]
+pub struct Strided<A> {
+    ptr: *mut A,
+    stride: usize,
+}
+
+impl<A> Strided<A> {
+    #[inline]
+    fn new(ptr: *mut A, stride: usize) -> Self {
+        Self {
+            ptr: ptr,
+            stride: stride,
+        }
+    }
+
+    /// Returns a pointer to the value at position `index` in the sequence.
+    ///
+    /// The pointer is only valid to dereference if `index` lies within the
+    /// storage the sequence points into.
+    #[inline]
+    pub fn get(self, index: usize) -> *mut A {
+        (self.ptr as *mut u8).wrapping_add(index * self.stride) as *mut A
+    }
+}
+
+impl<A> Clone for Strided<A> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<A> Copy for Strided<A> {}
+
+
+/// A raw view of the node storage of a linked list set.
+///
+/// The iterators over the elements of a set read the links, list slots,
+/// and items of the nodes through the view, independently of the layout of
+/// the storage.
+#[derive(Debug)]
+pub struct RawNodes<T, Ix> {
+    pub nodes: Strided<Node<Ix>>,
+    pub node_lists: Strided<Ix>,
+    pub items: Strided<T>,
+    pub length: usize,
+}
+
+impl<T, Ix: IndexType> RawNodes<T, Ix> {
+    /// Returns the links of the node at position `index`.
+    ///
+    /// # Safety
+    ///
+    /// The storage the view was taken from must outlive `'a`, and must not
+    /// be mutated during `'a`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub unsafe fn node<'a>(&self, index: usize) -> &'a Node<Ix> {
+        assert!(index < self.length);
+
+        &*self.nodes.get(index)
+    }
+
+    /// Returns the position of the linked list the node at position `index`
+    /// belongs to in the list storage of the set.
+    ///
+    /// # Safety
+    ///
+    /// The storage the view was taken from must be alive.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub unsafe fn node_list(&self, index: usize) -> Ix {
+        assert!(index < self.length);
+
+        *self.node_lists.get(index)
+    }
+
+    /// Returns the item of the node at position `index`.
+    ///
+    /// # Safety
+    ///
+    /// The storage the view was taken from must outlive `'a`, and the item
+    /// must not be mutated during `'a`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub unsafe fn item<'a>(&self, index: usize) -> &'a T {
+        assert!(index < self.length);
+
+        &*self.items.get(index)
+    }
+
+    /// Returns a mutable reference to the item of the node at position
+    /// `index`.
+    ///
+    /// # Safety
+    ///
+    /// The view must come from a mutable borrow of the storage that outlives
+    /// `'a`, and no other reference to the item may exist during `'a`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of bounds.
+    #[inline]
+    pub unsafe fn item_mut<'a>(&self, index: usize) -> &'a mut T {
+        assert!(index < self.length);
+
+        &mut *self.items.get(index)
+    }
+}
+
+impl<T, Ix> Clone for RawNodes<T, Ix> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, Ix> Copy for RawNodes<T, Ix> {}
+
+
+/// The storage for the nodes of a linked list set.
+///
+/// Every node consists of its links, the position of the linked list it 
+/// belongs to, and its item. A node storage keeps the three parts of a node at the
+/// same position, and moves them together.
+pub trait NodeStorage<T, Ix>: Default {
+    /// Create an empty node storage with space for at least `capacity` nodes.
+    fn with_capacity(capacity: usize) -> Self;
+
+    /// Returns the number of nodes in the storage.
+    fn len(&self) -> usize;
+
+    /// Returns the number of nodes the storage can hold without reallocating.
+    fn capacity(&self) -> usize;
+
+    /// Reserve space for at least `additional` more nodes.
+    fn reserve(&mut self, additional: usize);
+
+    /// Try to reserve space for at least `additional` more nodes.
+    fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError>;
+
+    /// Shrink the capacity of the storage as much as possible.
+    fn shrink_to_fit(&mut self);
+
+    /// Remove every node, dropping the items.
+    fn clear(&mut self);
+
+    /// Append a node to the end of the storage.
+    fn push(&mut self, node: Node<Ix>, list_slot: Ix, item: T);
+
+    /// Swap the nodes at two positions.
+    fn swap(&mut self, a: usize, b: usize);
+
+    /// Remove the node at position `index`, and move the last node into its
+    /// place.
+    fn swap_remove(&mut self, index: usize) -> (Node<Ix>, T);
+
+    /// Returns the links of the node at position `index`.
+    fn node(&self, index: usize) -> &Node<Ix>;
+
+    /// Returns the links of the node at position `index` mutably.
+    fn node_mut(&mut self, index: usize) -> &mut Node<Ix>;
+
+    /// Returns the position of the linked list the node at position `index`
+    /// belongs to in the list storage of the set.
+    fn node_list(&self, index: usize) -> Ix;
+
+    /// Returns the position of the linked list the node at position `index`
+    /// belongs to in the list storage of the set mutably.
+    fn node_list_mut(&mut self, index: usize) -> &mut Ix;
+
+    /// Returns the item of the node at position `index`.
+    fn item(&self, index: usize) -> &T;
+
+    /// Returns the item of the node at position `index` mutably.
+    fn item_mut(&mut self, index: usize) -> &mut T;
+
+    /// Returns the items of the nodes at two distinct positions mutably.
+    fn item_pair_mut(&mut self, a: usize, b: usize) -> (&mut T, &mut T);
+
+    /// Convert the storage into the items of its nodes, in storage order.
+    fn into_items(self) -> Vec<T>;
+
+    /// Returns a raw view of the storage for reading.
+    fn raw(&self) -> RawNodes<T, Ix>;
+
+    /// Returns a raw view of the storage for reading and writing items.
+    fn raw_mut(&mut self) -> RawNodes<T, Ix>;
+
+    /// Remove every node without dropping the items.
+    ///
+    /// # Safety
+    ///
+    /// The caller takes over the items, which are still in place in memory,
+    /// and must drop each of them exactly once.
+    unsafe fn forget_all(&mut self);
+}
+
+
+/// A node of a linked list set stored together with the position of its 
+/// linked list and its item.
+#[derive(Clone, Debug)]
+pub(crate) struct AosNode<T, Ix> {
+    links: Node<Ix>,
+    list_slot: Ix,
+    item: T,
+}
+
+/// The array-of-structures node storage of a linked list set.
+///
+/// Every node is stored in one vector, with the item inline with the links
+/// and the list slot of the node. Walking a list touches one place in
+/// memory per element, which suits small items. This is the default node
+/// storage of `LinkedListSet`.
+#[derive(Clone, Debug)]
+pub struct AosStorage<T, Ix = u32> {
+    nodes: Vec<AosNode<T, Ix>>,
+}
+
+impl<T, Ix> AosStorage<T, Ix> {
+    fn raw_parts(nodes: *mut AosNode<T, Ix>, length: usize) -> RawNodes<T, Ix> {
+        let base = nodes as *mut u8;
+        let stride = mem::size_of::<AosNode<T, Ix>>();
+
+        RawNodes {
+            nodes: Strided::new(
+                base.wrapping_add(mem::offset_of!(AosNode<T, Ix>, links)) as *mut Node<Ix>,
+                stride
+            ),
+            node_lists: Strided::new(
+                base.wrapping_add(mem::offset_of!(AosNode<T, Ix>, list_slot)) as *mut Ix,
+                stride
+            ),
+            items: Strided::new(
+                base.wrapping_add(mem::offset_of!(AosNode<T, Ix>, item)) as *mut T,
+                stride
+            ),
+            length: length,
+        }
+    }
+}
+
+impl<T, Ix> Default for AosStorage<T, Ix> {
+    fn default() -> Self {
+        Self {
+            nodes: Vec::new(),
+        }
+    }
+}
+
+impl<T, Ix: IndexType> NodeStorage<T, Ix> for AosStorage<T, Ix> {
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            nodes: Vec::with_capacity(capacity),
+        }
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    fn capacity(&self) -> usize {
+        self.nodes.capacity()
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.nodes.reserve(additional);
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.nodes.try_reserve(additional)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.nodes.shrink_to_fit();
+    }
+
+    fn clear(&mut self) {
+        self.nodes.clear();
+    }
+
+    #[inline]
+    fn push(&mut self, node: Node<Ix>, list_slot: Ix, item: T) {
+        self.nodes.push(AosNode {
+            links: node,
+            list_slot: list_slot,
+            item: item,
+        });
+    }
+
+    #[inline]
+    fn swap(&mut self, a: usize, b: usize) {
+        self.nodes.swap(a, b);
+    }
+
+    #[inline]
+    fn swap_remove(&mut self, index: usize) -> (Node<Ix>, T) {
+        let node = self.nodes.swap_remove(index);
+
+        (node.links, node.item)
+    }
+
+    #[inline]
+    fn node(&self, index: usize) -> &Node<Ix> {
+        &self.nodes[index].links
+    }
+
+    #[inline]
+    fn node_mut(&mut self, index: usize) -> &mut Node<Ix> {
+        &mut self.nodes[index].links
+    }
+
+    #[inline]
+    fn node_list(&self, index: usize) -> Ix {
+        self.nodes[index].list_slot
+    }
+
+    #[inline]
+    fn node_list_mut(&mut self, index: usize) -> &mut Ix {
+        &mut self.nodes[index].list_slot
+    }
+
+    #[inline]
+    fn item(&self, index: usize) -> &T {
+        &self.nodes[index].item
+    }
+
+    #[inline]
+    fn item_mut(&mut self, index: usize) -> &mut T {
+        &mut self.nodes[index].item
+    }
+
+    #[inline]
+    fn item_pair_mut(&mut self, a: usize, b: usize) -> (&mut T, &mut T) {
+        assert_ne!(a, b);
+        if a < b {
+            let (left, right) = self.nodes.split_at_mut(b);
+            (&mut left[a].item, &mut right[0].item)
+        } else {
+            let (left, right) = self.nodes.split_at_mut(a);
+            (&mut right[0].item, &mut left[b].item)
+        }
+    }
+
+    fn into_items(self) -> Vec<T> {
+        self.nodes.into_iter().map(|node| node.item).collect()
+    }
+
+    #[inline]
+    fn raw(&self) -> RawNodes<T, Ix> {
+        Self::raw_parts(self.nodes.as_ptr() as *mut AosNode<T, Ix>, self.nodes.len())
+    }
+
+    #[inline]
+    fn raw_mut(&mut self) -> RawNodes<T, Ix> {
+        Self::raw_parts(self.nodes.as_mut_ptr(), self.nodes.len())
+    }
+
+    unsafe fn forget_all(&mut self) {
+        self.nodes.set_len(0);
+    }
+}
+
+
+/// The structure-of-arrays node storage of a linked list set.
+///
+/// The links, the list slots, and the items of the nodes are stored in
+/// three separate vectors, at the same position in each. Walking a list only
+/// touches the links of the nodes it passes over, which suits large items,
+/// and the items of a run of consecutive nodes form a slice. See
+/// `SoaLinkedListSet`.
+#[derive(Clone, Debug)]
+pub struct SoaStorage<T, Ix = u32> {
+    nodes: Vec<Node<Ix>>,
+    node_lists: Vec<Ix>,
+    items: Vec<T>,
+}
+
+impl<T, Ix> SoaStorage<T, Ix> {
+    /// Returns the items of every node, in storage order.
+    #[inline]
+    pub(crate) fn items(&self) -> &[T] {
+        &self.items
+    }
+}
+
+impl<T, Ix> Default for SoaStorage<T, Ix> {
+    fn default() -> Self {
+        Self {
+            nodes: Vec::new(),
+            node_lists: Vec::new(),
+            items: Vec::new(),
+        }
+    }
+}
+
+impl<T, Ix: IndexType> NodeStorage<T, Ix> for SoaStorage<T, Ix> {
+    fn with_capacity(capacity: usize) -> Self {
+        Self {
+            nodes: Vec::with_capacity(capacity),
+            node_lists: Vec::with_capacity(capacity),
+            items: Vec::with_capacity(capacity),
+        }
+    }
+
+    #[inline]
+    fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    fn capacity(&self) -> usize {
+        self.nodes.capacity()
+            .min(self.node_lists.capacity())
+            .min(self.items.capacity())
+    }
+
+    fn reserve(&mut self, additional: usize) {
+        self.nodes.reserve(additional);
+        self.node_lists.reserve(additional);
+        self.items.reserve(additional);
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.nodes.try_reserve(additional)?;
+        self.node_lists.try_reserve(additional)?;
+        self.items.try_reserve(additional)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.nodes.shrink_to_fit();
+        self.node_lists.shrink_to_fit();
+        self.items.shrink_to_fit();
+    }
+
+    fn clear(&mut self) {
+        self.nodes.clear();
+        self.node_lists.clear();
+        self.items.clear();
+    }
+
+    #[inline]
+    fn push(&mut self, node: Node<Ix>, list_slot: Ix, item: T) {
+        self.nodes.push(node);
+        self.node_lists.push(list_slot);
+        self.items.push(item);
+    }
+
+    #[inline]
+    fn swap(&mut self, a: usize, b: usize) {
+        self.nodes.swap(a, b);
+        self.node_lists.swap(a, b);
+        self.items.swap(a, b);
+    }
+
+    #[inline]
+    fn swap_remove(&mut self, index: usize) -> (Node<Ix>, T) {
+        let node = self.nodes.swap_remove(index);
+        self.node_lists.swap_remove(index);
+        let item = self.items.swap_remove(index);
+
+        (node, item)
+    }
+
+    #[inline]
+    fn node(&self, index: usize) -> &Node<Ix> {
+        &self.nodes[index]
+    }
+
+    #[inline]
+    fn node_mut(&mut self, index: usize) -> &mut Node<Ix> {
+        &mut self.nodes[index]
+    }
+
+    #[inline]
+    fn node_list(&self, index: usize) -> Ix {
+        self.node_lists[index]
+    }
+
+    #[inline]
+    fn node_list_mut(&mut self, index: usize) -> &mut Ix {
+        &mut self.node_lists[index]
+    }
+
+    #[inline]
+    fn item(&self, index: usize) -> &T {
+        &self.items[index]
+    }
+
+    #[inline]
+    fn item_mut(&mut self, index: usize) -> &mut T {
+        &mut self.items[index]
+    }
+
+    #[inline]
+    fn item_pair_mut(&mut self, a: usize, b: usize) -> (&mut T, &mut T) {
+        assert_ne!(a, b);
+        if a < b {
+            let (left, right) = self.items.split_at_mut(b);
+            (&mut left[a], &mut right[0])
+        } else {
+            let (left, right) = self.items.split_at_mut(a);
+            (&mut right[0], &mut left[b])
+        }
+    }
+
+    fn into_items(self) -> Vec<T> {
+        self.items
+    }
+
+    #[inline]
+    fn raw(&self) -> RawNodes<T, Ix> {
+        RawNodes {
+            nodes: Strided::new(self.nodes.as_ptr() as *mut Node<Ix>, mem::size_of::<Node<Ix>>()),
+            node_lists: Strided::new(self.node_lists.as_ptr() as *mut Ix, mem::size_of::<Ix>()),
+            items: Strided::new(self.items.as_ptr() as *mut T, mem::size_of::<T>()),
+            length: self.nodes.len(),
+        }
+    }
+
+    #[inline]
+    fn raw_mut(&mut self) -> RawNodes<T, Ix> {
+        RawNodes {
+            nodes: Strided::new(self.nodes.as_mut_ptr(), mem::size_of::<Node<Ix>>()),
+            node_lists: Strided::new(self.node_lists.as_mut_ptr(), mem::size_of::<Ix>()),
+            items: Strided::new(self.items.as_mut_ptr(), mem::size_of::<T>()),
+            length: self.nodes.len(),
+        }
+    }
+
+    unsafe fn forget_all(&mut self) {
+        self.nodes.set_len(0);
+        self.node_lists.set_len(0);
+        self.items.set_len(0);
+    }
+}
@@ -0,0 +1,215 @@
+extern crate list_set;
+
+
+use list_set::*;
+
+
+fn linked_list_set() -> (LinkedListSet<usize>, [ListIndex; 3]) {
+    let mut set = LinkedListSet::new();
+    let list_indices = [
+        set.new_list(),
+        set.new_list(),
+        set.new_list()
+    ];
+    // Interleave the lists so the nodes of each list are spread out in the 
+    // underlying storage.
+    for item in 0..10 {
+        for (i, list_index) in list_indices.iter().copied().enumerate() {
+            set.push_back(list_index, 100 * i + item);
+        }
+    }
+
+    (set, list_indices)
+}
+
+/// A cursor should visit every element of a list in order, and wrap around
+/// through the ghost non-element.
+#[test]
+fn test_cursor_move_next_visits_every_element() {
+    let (set, list_indices) = linked_list_set();
+    let mut cursor = set.cursor_front(list_indices[1]);
+    let mut result = Vec::new();
+    while let Some(item) = cursor.current() {
+        assert_eq!(cursor.index(), Some(result.len()));
+        result.push(*item);
+        cursor.move_next();
+    }
+    let expected: Vec<usize> = (100..110).collect();
+
+    assert_eq!(result, expected);
+    assert_eq!(cursor.index(), None);
+
+    cursor.move_next();
+
+    assert_eq!(cursor.current(), Some(&100));
+    assert_eq!(cursor.index(), Some(0));
+}
+
+/// A cursor should visit every element of a list in reverse order.
+#[test]
+fn test_cursor_move_prev_visits_every_element() {
+    let (set, list_indices) = linked_list_set();
+    let mut cursor = set.cursor_back(list_indices[2]);
+    let mut result = Vec::new();
+    while let Some(item) = cursor.current() {
+        assert_eq!(cursor.index(), Some(9 - result.len()));
+        result.push(*item);
+        cursor.move_prev();
+    }
+    let expected: Vec<usize> = (200..210).rev().collect();
+
+    assert_eq!(result, expected);
+
+    cursor.move_prev();
+
+    assert_eq!(cursor.current(), Some(&209));
+    assert_eq!(cursor.index(), Some(9));
+}
+
+/// A cursor over an empty list should point to the ghost non-element.
+#[test]
+fn test_cursor_empty_list() {
+    let mut set: LinkedListSet<usize> = LinkedListSet::new();
+    let list_index = set.new_list();
+    let mut cursor = set.cursor_front(list_index);
+
+    assert_eq!(cursor.current(), None);
+    assert_eq!(cursor.peek_next(), None);
+    assert_eq!(cursor.peek_prev(), None);
+
+    cursor.move_next();
+
+    assert_eq!(cursor.current(), None);
+}
+
+/// Removing every other element through a cursor should stay correct even 
+/// though each removal relocates a node inside the underlying storage.
+#[test]
+fn test_cursor_remove_current_across_relocations() {
+    let (mut set, list_indices) = linked_list_set();
+    {
+        let mut cursor = set.cursor_front_mut(list_indices[0]);
+        while cursor.current().is_some() {
+            cursor.remove_current();
+            cursor.move_next();
+        }
+    }
+    let expected = [
+        vec![1, 3, 5, 7, 9],
+        (100..110).collect::<Vec<usize>>(),
+        (200..210).collect::<Vec<usize>>(),
+    ];
+
+    for (list_index, expected) in list_indices.iter().zip(expected.iter()) {
+        let result: Vec<usize> = set.iter(*list_index).copied().collect();
+
+        assert_eq!(&result, expected);
+        assert_eq!(set.len(*list_index), expected.len());
+    }
+}
+
+/// Removing the elements of a list from the back through a cursor should 
+/// empty the list and leave the cursor at the ghost non-element.
+#[test]
+fn test_cursor_remove_current_from_back() {
+    let (mut set, list_indices) = linked_list_set();
+    {
+        let mut cursor = set.cursor_back_mut(list_indices[1]);
+        for expected in (100..110).rev() {
+            assert_eq!(cursor.remove_current(), Some(expected));
+            cursor.move_prev();
+        }
+
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.remove_current(), None);
+    }
+
+    assert!(set.list_is_empty(list_indices[1]));
+    assert_eq!(set.node_count(), 20);
+}
+
+/// Inserting through a cursor at the ghost non-element should insert at the 
+/// ends of the list.
+#[test]
+fn test_cursor_insert_at_ghost() {
+    let mut set = LinkedListSet::new();
+    let list_index = set.new_list();
+    set.extend(list_index, vec![2, 3]);
+    {
+        let mut cursor = set.cursor_front_mut(list_index);
+        cursor.move_prev();
+
+        assert_eq!(cursor.current(), None);
+
+        cursor.insert_after(1);
+        cursor.insert_before(4);
+
+        assert_eq!(cursor.peek_next().copied(), Some(1));
+        assert_eq!(cursor.peek_prev().copied(), Some(4));
+    }
+    let result: Vec<usize> = set.iter(list_index).copied().collect();
+
+    assert_eq!(result, vec![1, 2, 3, 4]);
+}
+
+/// Splitting a list with a cursor should move the elements after the cursor 
+/// into a new list of the same set.
+#[test]
+fn test_cursor_split_after() {
+    let (mut set, list_indices) = linked_list_set();
+    let new_list_index = {
+        let mut cursor = set.cursor_front_mut(list_indices[1]);
+        cursor.move_next();
+        cursor.move_next();
+        cursor.split_after()
+    };
+    let result: Vec<usize> = set.iter(list_indices[1]).copied().collect();
+    let new_result: Vec<usize> = set.iter(new_list_index).copied().collect();
+
+    assert_eq!(result, vec![100, 101, 102]);
+    assert_eq!(new_result, (103..110).collect::<Vec<usize>>());
+    assert_eq!(set.len(list_indices[1]), 3);
+    assert_eq!(set.len(new_list_index), 7);
+
+    set.pop_front(new_list_index);
+    set.push_back(list_indices[1], 999);
+
+    assert_eq!(set.front(new_list_index), Some(&104));
+    assert_eq!(set.back(list_indices[1]), Some(&999));
+}
+
+/// Splicing a list with a cursor should move every element of the other list 
+/// into the list at the cursor, and leave the other list empty.
+#[test]
+fn test_cursor_splice_before_and_after() {
+    let (mut set, list_indices) = linked_list_set();
+    {
+        let mut cursor = set.cursor_front_mut(list_indices[0]);
+        cursor.move_next();
+        cursor.splice_before(list_indices[1]);
+
+        assert_eq!(cursor.index(), Some(11));
+        assert_eq!(cursor.current().copied(), Some(1));
+
+        cursor.splice_after(list_indices[2]);
+
+        assert_eq!(cursor.peek_next().copied(), Some(200));
+    }
+    let mut expected = vec![0];
+    expected.extend(100..110);
+    expected.push(1);
+    expected.extend(200..210);
+    expected.extend(2..10);
+    let result: Vec<usize> = set.iter(list_indices[0]).copied().collect();
+
+    assert_eq!(result, expected);
+    assert_eq!(set.len(list_indices[0]), 30);
+    assert!(set.list_is_empty(list_indices[1]));
+    assert!(set.list_is_empty(list_indices[2]));
+    assert!(set.contains_list(list_indices[1]));
+
+    let result_rev: Vec<usize> = set.iter(list_indices[0]).rev().copied().collect();
+    expected.reverse();
+
+    assert_eq!(result_rev, expected);
+}
@@ -0,0 +1,106 @@
+extern crate list_set;
+
+
+use list_set::*;
+
+
+fn linked_list_set() -> (LinkedListSet<usize>, [ListIndex; 4], Vec<NodeHandle>) {
+    let mut set = LinkedListSet::new();
+    let list_indices = [set.new_list(), set.new_list(), set.new_list(), set.new_list()];
+    let mut handles = Vec::new();
+    for item in 0..40 {
+        let list_index = list_indices[(item * 3) % 4];
+        let handle = if item % 2 == 0 {
+            set.push_back(list_index, item)
+        } else {
+            set.push_front(list_index, item)
+        };
+        handles.push(handle);
+    }
+    set.remove_node(handles[5]);
+    set.clear(list_indices[2]);
+
+    (set, list_indices, handles)
+}
+
+/// Each list of a frozen set should be a slice with the same elements in the
+/// same order as the list in the original set.
+#[test]
+fn test_freeze_lists_are_slices_in_list_order() {
+    let (set, list_indices, _) = linked_list_set();
+    let expected: Vec<Vec<usize>> = list_indices.iter()
+        .map(|list_index| set.iter(*list_index).copied().collect())
+        .collect();
+    let node_count = set.node_count();
+    let frozen = set.freeze();
+
+    for (list_index, items) in list_indices.iter().zip(expected.iter()) {
+        assert_eq!(frozen.as_slice(*list_index), &items[..]);
+        assert_eq!(frozen.len(*list_index), items.len());
+    }
+    assert_eq!(frozen.as_slice(list_indices[2]), &[] as &[usize]);
+    assert_eq!(frozen.items(), &expected.concat()[..]);
+    assert_eq!(frozen.node_count(), node_count);
+    assert_eq!(frozen.list_count(), 4);
+}
+
+/// The list indices and node handles of a set should stay valid through 
+/// freezing and thawing it.
+#[test]
+fn test_freeze_and_thaw_preserve_indices_and_handles() {
+    let (mut set, list_indices, handles) = linked_list_set();
+    set.remove_list(list_indices[3]);
+    let expected: Vec<Vec<usize>> = list_indices[..3].iter()
+        .map(|list_index| set.iter(*list_index).copied().collect())
+        .collect();
+    let frozen = set.freeze();
+
+    assert!(!frozen.contains_list(list_indices[3]));
+    assert_eq!(frozen.try_as_slice(list_indices[3]), Err(ListSetError::StaleHandle(list_indices[3])));
+    for (item, handle) in handles.iter().enumerate() {
+        if item == 5 || (item * 3) % 4 >= 2 {
+            assert_eq!(frozen.get(*handle), None);
+        } else {
+            assert_eq!(frozen.get(*handle), Some(&item));
+        }
+    }
+
+    let mut set = frozen.thaw();
+
+    for (list_index, items) in list_indices.iter().zip(expected.iter()) {
+        let result: Vec<usize> = set.iter(*list_index).copied().collect();
+        let result_rev: Vec<usize> = set.iter(*list_index).rev().copied().collect();
+
+        assert_eq!(&result, items);
+        assert_eq!(result_rev, items.iter().rev().copied().collect::<Vec<usize>>());
+    }
+    assert_eq!(set.get(handles[4]), Some(&4));
+    assert!(!set.contains_list(list_indices[3]));
+
+    let new_list_index = set.new_list();
+    set.push_back(list_indices[2], 100);
+    set.remove_node(handles[0]);
+    set.push_back(new_list_index, 200);
+
+    assert_ne!(new_list_index, list_indices[3]);
+    assert_eq!(set.back(list_indices[2]), Some(&100));
+    assert_eq!(set.front(new_list_index), Some(&200));
+    assert_eq!(set.get(handles[0]), None);
+}
+
+/// A node handle created by a different set should not point to an element 
+/// of a frozen linked list set.
+#[test]
+fn test_frozen_list_set_rejects_foreign_node_handle() {
+    let mut set = LinkedListSet::new();
+    let mut other_set = LinkedListSet::new();
+    let list_index = set.new_list();
+    let other_list_index = other_set.new_list();
+    let handle = set.push_back(list_index, 1);
+    other_set.push_back(other_list_index, 2);
+    let frozen = set.freeze();
+    let other_frozen = other_set.freeze();
+
+    assert_eq!(other_frozen.get(handle), None);
+    assert_eq!(frozen.get(handle), Some(&1));
+}
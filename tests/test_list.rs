@@ -5,6 +5,10 @@ use list_set::*;
 use std::collections::{
     HashMap,
 };
+use std::panic::{
+    self,
+    AssertUnwindSafe,
+};
 
 
 /// An empty linked list set should have no elements inside it.
@@ -816,3 +820,1832 @@ fn test_remove_list_list_not_in_set() {
     assert_eq!(result, expected);
 }
 
+
+/// A node handle should keep pointing to the same element after other elements
+/// are removed from the set, even though the removals relocate nodes inside the
+/// underlying storage.
+#[test]
+fn test_node_handles_survive_removals() {
+    let mut set = LinkedListSet::new();
+    let list_indices = [
+        set.new_list(),
+        set.new_list(),
+        set.new_list()   
+    ];
+    let mut handles = Vec::new();
+    for item in 0..30 {
+        let list_index = list_indices[item % list_indices.len()];
+        handles.push((set.push_back(list_index, item), item));
+    }
+
+    for (handle, _) in handles.iter().filter(|(_, item)| item % 3 == 1) {
+        assert!(set.remove_node(*handle).is_some());
+    }
+    set.pop_front(list_indices[0]);
+    set.pop_back(list_indices[2]);
+
+    for (handle, item) in handles.iter().copied() {
+        match item {
+            0 | 29 => assert_eq!(set.get(handle), None),
+            _ if item % 3 == 1 => assert_eq!(set.get(handle), None),
+            _ => assert_eq!(set.get(handle), Some(&item)),
+        }
+    }
+}
+
+/// Removing an element through its node handle should unlink only that 
+/// element from its list.
+#[test]
+fn test_remove_node() {
+    let mut set = LinkedListSet::new();
+    let list_index = set.new_list();
+    let handles: Vec<NodeHandle> = (0..10)
+        .map(|item| set.push_back(list_index, item))
+        .collect();
+
+    assert_eq!(set.remove_node(handles[4]), Some(4));
+    assert_eq!(set.remove_node(handles[0]), Some(0));
+    assert_eq!(set.remove_node(handles[9]), Some(9));
+
+    let expected = vec![1, 2, 3, 5, 6, 7, 8];
+    let result: Vec<usize> = set.iter(list_index).copied().collect();
+
+    assert_eq!(result, expected);
+    assert_eq!(set.len(list_index), expected.len());
+}
+
+/// A node handle to a removed element should not point to a new element 
+/// that reuses its storage.
+#[test]
+fn test_stale_node_handle_does_not_alias_new_element() {
+    let mut set = LinkedListSet::new();
+    let list_index = set.new_list();
+    let handle = set.push_back(list_index, 1);
+    set.remove_node(handle);
+    let new_handle = set.push_back(list_index, 2);
+
+    assert!(!set.contains_node(handle));
+    assert_eq!(set.get(handle), None);
+    assert_eq!(set.remove_node(handle), None);
+    assert_eq!(set.get(new_handle), Some(&2));
+}
+
+/// Clearing the set should invalidate every node handle.
+#[test]
+fn test_clear_all_invalidates_node_handles() {
+    let mut set = LinkedListSet::new();
+    let list_index = set.new_list();
+    let handles: Vec<NodeHandle> = (0..10)
+        .map(|item| set.push_back(list_index, item))
+        .collect();
+    set.clear_all();
+
+    assert!(handles.iter().all(|handle| !set.contains_node(*handle)));
+}
+
+/// A list index should stop pointing to a linked list once the list is removed
+/// from the set.
+#[test]
+fn test_removed_list_index_is_stale() {
+    let mut set: LinkedListSet<usize> = LinkedListSet::new();
+    let list_index = set.new_list();
+    set.extend(list_index, vec![1, 2, 3]);
+    set.remove_list(list_index);
+
+    assert!(!set.contains_list(list_index));
+    assert!(set.get_list(list_index).is_none());
+    assert!(set.front(list_index).is_none());
+    assert!(!set.remove_list(list_index));
+}
+
+/// Pushing to a removed list should panic without adding a node to the set,
+/// so the other lists stay intact.
+#[test]
+fn test_push_to_removed_list_leaves_set_intact() {
+    let mut set: LinkedListSet<usize> = LinkedListSet::new();
+    let list_index = set.new_list();
+    let removed_list_index = set.new_list();
+    set.extend(list_index, vec![1, 2, 3]);
+    set.remove_list(removed_list_index);
+    let push_back = panic::catch_unwind(AssertUnwindSafe(|| {
+        set.push_back(removed_list_index, 9);
+    }));
+    let push_front = panic::catch_unwind(AssertUnwindSafe(|| {
+        set.push_front(removed_list_index, 9);
+    }));
+
+    assert!(push_back.is_err());
+    assert!(push_front.is_err());
+    assert_eq!(set.node_count(), 3);
+    assert_eq!(set.len(list_index), 3);
+    assert_eq!(set.pop_front(list_index), Some(1));
+    assert_eq!(set.pop_back(list_index), Some(3));
+    assert_eq!(set.pop_front(list_index), Some(2));
+    assert_eq!(set.node_count(), 0);
+}
+
+/// A list index created by one linked list set should not point to a linked
+/// list in a different set.
+#[test]
+fn test_foreign_list_index_is_rejected() {
+    let mut set: LinkedListSet<usize> = LinkedListSet::new();
+    let mut other_set: LinkedListSet<usize> = LinkedListSet::new();
+    let list_index = set.new_list();
+    let other_list_index = other_set.new_list();
+
+    assert_ne!(list_index, other_list_index);
+    assert!(set.contains_list(list_index));
+    assert!(!set.contains_list(other_list_index));
+    assert!(!other_set.contains_list(list_index));
+}
+
+/// A node handle created by one linked list set should not point to an 
+/// element of a different set, even when the other set has an element in 
+/// the same handle slot.
+#[test]
+fn test_foreign_node_handle_is_rejected() {
+    let mut set: LinkedListSet<usize> = LinkedListSet::new();
+    let mut other_set: LinkedListSet<usize> = LinkedListSet::new();
+    let list_index = set.new_list();
+    let other_list_index = other_set.new_list();
+    let handle = set.push_back(list_index, 100);
+    let other_handle = other_set.push_back(other_list_index, 200);
+
+    assert_ne!(handle, other_handle);
+    assert!(!other_set.contains_node(handle));
+    assert_eq!(other_set.get(handle), None);
+    assert_eq!(other_set.get_mut(handle), None);
+    assert_eq!(other_set.remove_node(handle), None);
+    assert!(!other_set.move_node_to_front(handle, other_list_index));
+    assert_eq!(other_set.iter(other_list_index).copied().collect::<Vec<usize>>(), vec![200]);
+    assert_eq!(set.get(handle), Some(&100));
+    assert_eq!(other_set.get(other_handle), Some(&200));
+}
+
+/// Creating a list after removing another list should reuse the storage of the
+/// removed list without reviving the removed list index.
+#[test]
+fn test_new_list_after_remove_list_does_not_revive_removed_index() {
+    let mut set = LinkedListSet::new();
+    let removed_list_index = set.new_list();
+    set.extend(removed_list_index, vec![1, 2, 3]);
+    set.remove_list(removed_list_index);
+    let new_list_index = set.new_list();
+    set.extend(new_list_index, vec![4, 5, 6]);
+
+    assert_ne!(new_list_index, removed_list_index);
+    assert!(!set.contains_list(removed_list_index));
+    assert!(set.front(removed_list_index).is_none());
+    assert_eq!(set.front(new_list_index), Some(&4));
+}
+
+/// A clone of a set should reject the list indices of the original, and 
+/// hand out its own list indices for the same lists in the same order.
+#[test]
+fn test_clone_has_its_own_list_indices() {
+    let mut set = LinkedListSet::new();
+    let list_indices = [set.new_list(), set.new_list()];
+    set.extend(list_indices[0], vec![1, 2]);
+    set.extend(list_indices[1], vec![3]);
+    let mut clone = set.clone();
+    let clone_list_indices: Vec<ListIndex> = clone.list_indices().collect();
+    let new_list_index = set.new_list();
+    let new_clone_list_index = clone.new_list();
+
+    assert!(list_indices.iter().all(|list_index| !clone.contains_list(*list_index)));
+    assert!(clone_list_indices.iter().all(|list_index| !set.contains_list(*list_index)));
+    assert_eq!(clone.iter(clone_list_indices[0]).copied().collect::<Vec<usize>>(), vec![1, 2]);
+    assert_eq!(clone.iter(clone_list_indices[1]).copied().collect::<Vec<usize>>(), vec![3]);
+    assert_ne!(new_list_index, new_clone_list_index);
+    assert!(!clone.contains_list(new_list_index));
+    assert!(!set.contains_list(new_clone_list_index));
+}
+
+/// Creating and destroying lists repeatedly should not grow the list index 
+/// space, and every removed list index should stay stale.
+#[test]
+fn test_list_indices_are_recycled() {
+    let mut set: LinkedListSet<usize> = LinkedListSet::new();
+    let live_list_index = set.new_list();
+    let removed_list_index = set.new_list();
+    set.remove_list(removed_list_index);
+    let list_capacity = set.list_capacity();
+    let mut removed_list_indices = vec![removed_list_index];
+    for item in 0..100 {
+        let list_index = set.new_list();
+        set.push_back(list_index, item);
+        set.remove_list(list_index);
+        removed_list_indices.push(list_index);
+
+        assert_eq!(set.list_capacity(), list_capacity);
+    }
+
+    assert!(list_capacity < removed_list_indices.len());
+    assert_eq!(set.list_count(), 1);
+    assert!(set.contains_list(live_list_index));
+    assert!(removed_list_indices.iter().all(|list_index| !set.contains_list(*list_index)));
+}
+
+/// Clearing the set should invalidate every list index, with or without 
+/// resetting the list index allocator.
+#[test]
+fn test_clear_all_invalidates_list_indices() {
+    let mut set: LinkedListSet<usize> = LinkedListSet::new();
+    let list_indices = [set.new_list(), set.new_list(), set.new_list()];
+    set.clear_all();
+    let new_list_indices = [set.new_list(), set.new_list(), set.new_list()];
+
+    assert!(list_indices.iter().all(|list_index| !set.contains_list(*list_index)));
+    assert!(new_list_indices.iter().all(|list_index| set.contains_list(*list_index)));
+
+    set.clear_all_and_reset();
+    let reset_list_indices = [set.new_list(), set.new_list(), set.new_list()];
+
+    assert!(list_indices.iter().all(|list_index| !set.contains_list(*list_index)));
+    assert!(new_list_indices.iter().all(|list_index| !set.contains_list(*list_index)));
+    assert!(reset_list_indices.iter().all(|list_index| set.contains_list(*list_index)));
+}
+
+/// The fallible operations should report a removed list as a stale handle
+/// instead of panicking.
+#[test]
+fn test_try_operations_on_removed_list_report_stale_handle() {
+    let mut set = LinkedListSet::new();
+    let list_index = set.new_list();
+    set.extend(list_index, vec![1, 2, 3]);
+    set.remove_list(list_index);
+    let expected = ListSetError::StaleHandle(list_index);
+
+    assert_eq!(set.try_len(list_index), Err(expected));
+    assert_eq!(set.try_list_is_empty(list_index), Err(expected));
+    assert!(set.try_iter(list_index).is_err());
+    assert!(set.try_iter_mut(list_index).is_err());
+    assert_eq!(set.try_push_back(list_index, 4).err(), Some(expected));
+    assert_eq!(set.try_push_front(list_index, 4).err(), Some(expected));
+    assert_eq!(set.try_pop_front(list_index), Err(expected));
+    assert_eq!(set.try_pop_back(list_index), Err(expected));
+    assert_eq!(set.try_remove(list_index, 0), Err(expected));
+    assert_eq!(set.try_clear(list_index), Err(expected));
+    assert_eq!(set.try_extend(list_index, vec![4, 5]), Err(expected));
+    assert_eq!(set.node_count(), 0);
+}
+
+/// The fallible operations should report a list index from a different set as
+/// an unknown list instead of panicking.
+#[test]
+fn test_try_operations_on_foreign_list_report_unknown_list() {
+    let mut set: LinkedListSet<usize> = LinkedListSet::new();
+    let mut other_set: LinkedListSet<usize> = LinkedListSet::new();
+    set.new_list();
+    let list_index = other_set.new_list();
+    let expected = ListSetError::UnknownList(list_index);
+
+    assert_eq!(set.try_len(list_index), Err(expected));
+    assert_eq!(set.try_push_back(list_index, 1).err(), Some(expected));
+    assert_eq!(set.try_pop_front(list_index), Err(expected));
+    assert_eq!(set.node_count(), 0);
+}
+
+/// Removing an item past the end of a list should report the position and 
+/// the length of the list.
+#[test]
+fn test_try_remove_out_of_bounds() {
+    let mut set = LinkedListSet::new();
+    let list_index = set.new_list();
+    set.extend(list_index, vec![1, 2, 3]);
+
+    assert_eq!(
+        set.try_remove(list_index, 3), 
+        Err(ListSetError::IndexOutOfBounds { index: 3, length: 3 })
+    );
+    assert_eq!(set.try_remove(list_index, 2), Ok(3));
+    assert_eq!(set.len(list_index), 2);
+}
+
+/// Reserving an impossible amount of storage should report an allocation
+/// failure, or a capacity overflow if the nodes would not fit in the index 
+/// type, and leave the set unchanged.
+#[test]
+fn test_try_reserve_capacity_overflow() {
+    let mut set = LinkedListSet::new();
+    let list_index = set.new_list();
+    set.extend(list_index, vec![1, 2, 3]);
+
+    assert_eq!(set.try_reserve(usize::MAX), Err(ListSetError::CapacityOverflow));
+    assert_eq!(set.try_reserve_lists(usize::MAX), Err(ListSetError::AllocationFailed));
+
+    let result: Vec<usize> = set.iter(list_index).copied().collect();
+
+    assert_eq!(result, vec![1, 2, 3]);
+}
+
+/// After reserving storage, pushing items up to the reserved capacity should 
+/// not reallocate the underlying storage.
+#[test]
+fn test_reserve_then_push_does_not_reallocate() {
+    let mut set = LinkedListSet::new();
+    set.reserve_lists(4);
+    set.reserve(100);
+    let list_capacity = set.list_capacity();
+    let node_capacity = set.node_capacity();
+    let list_indices: Vec<ListIndex> = (0..4)
+        .map(|_| set.try_new_list().unwrap())
+        .collect();
+    for item in 0..100 {
+        set.try_push_back(list_indices[item % 4], item).unwrap();
+    }
+
+    assert_eq!(set.list_capacity(), list_capacity);
+    assert_eq!(set.node_capacity(), node_capacity);
+    assert_eq!(set.node_count(), 100);
+}
+
+/// Indexed access should find the same elements as iterating over the list,
+/// whichever end of the list is closer.
+#[test]
+fn test_get_at_matches_iteration_order() {
+    let mut set = LinkedListSet::new();
+    let list_indices = [set.new_list(), set.new_list()];
+    for item in 0..21 {
+        set.push_back(list_indices[0], item);
+        set.push_front(list_indices[1], item);
+    }
+
+    for list_index in list_indices.iter().copied() {
+        for (at, expected) in set.iter(list_index).enumerate() {
+            assert_eq!(set.get_at(list_index, at), Some(expected));
+        }
+
+        assert_eq!(set.get_at(list_index, 21), None);
+    }
+}
+
+/// Inserting into a list at every position should produce the same list as
+/// inserting into a vector at the same positions.
+#[test]
+fn test_insert_matches_vec_insert() {
+    let mut set = LinkedListSet::new();
+    let list_index = set.new_list();
+    let mut expected = Vec::new();
+    for item in 0..20 {
+        let at = (item * 7) % (expected.len() + 1);
+        expected.insert(at, item);
+        assert!(set.insert(list_index, at, item).is_ok());
+    }
+    let result: Vec<usize> = set.iter(list_index).copied().collect();
+
+    assert_eq!(result, expected);
+    assert_eq!(set.len(list_index), expected.len());
+    assert_eq!(set.insert(list_index, 21, 21), Err(21));
+    assert_eq!(set.len(list_index), expected.len());
+}
+
+/// Removing from a list at positions near the back should produce the same
+/// list as removing from a vector at the same positions.
+#[test]
+fn test_remove_near_back_matches_vec_remove() {
+    let mut set = LinkedListSet::new();
+    let list_index = set.new_list();
+    let mut expected: Vec<usize> = (0..20).collect();
+    set.extend(list_index, expected.iter().copied());
+    while expected.len() > 1 {
+        let at = expected.len() - 2;
+        let expected_item = expected.remove(at);
+
+        assert_eq!(set.remove(list_index, at), Some((expected_item, at)));
+    }
+    let result: Vec<usize> = set.iter(list_index).copied().collect();
+
+    assert_eq!(result, expected);
+}
+
+/// Swapping elements should relink the nodes, so the node handles keep
+/// pointing to the same items, including when the elements are adjacent.
+#[test]
+fn test_swap_keeps_node_handles() {
+    let mut set = LinkedListSet::new();
+    let list_index = set.new_list();
+    let handles: Vec<NodeHandle> = (0..6)
+        .map(|item| set.push_back(list_index, item))
+        .collect();
+
+    assert!(set.swap(list_index, 0, 5).is_ok());
+    assert!(set.swap(list_index, 2, 3).is_ok());
+    assert!(set.swap(list_index, 4, 3).is_ok());
+    assert!(set.swap(list_index, 1, 1).is_ok());
+
+    let expected = vec![5, 1, 3, 4, 2, 0];
+    let result: Vec<usize> = set.iter(list_index).copied().collect();
+    let result_rev: Vec<usize> = set.iter(list_index).rev().copied().collect();
+
+    assert_eq!(result, expected);
+    assert_eq!(result_rev, expected.iter().rev().copied().collect::<Vec<usize>>());
+    for (item, handle) in handles.iter().enumerate() {
+        assert_eq!(set.get(*handle), Some(&item));
+    }
+}
+
+/// Replacing an element should return the old element and leave the other
+/// elements unchanged.
+#[test]
+fn test_replace() {
+    let mut set = LinkedListSet::new();
+    let list_index = set.new_list();
+    set.extend(list_index, vec![0, 1, 2, 3]);
+
+    assert_eq!(set.replace(list_index, 3, 30), Ok(3));
+    assert_eq!(set.replace(list_index, 0, 10), Ok(0));
+    assert_eq!(set.replace(list_index, 4, 40), Err(40));
+
+    let result: Vec<usize> = set.iter(list_index).copied().collect();
+
+    assert_eq!(result, vec![10, 1, 2, 30]);
+}
+
+/// Inserting or replacing out of bounds should hand the item back, and 
+/// swapping out of bounds should report the offending position, all without
+/// changing the list.
+#[test]
+fn test_out_of_bounds_insert_replace_swap() {
+    let mut set = LinkedListSet::new();
+    let list_index = set.new_list();
+    set.extend(list_index, vec![String::from("a"), String::from("b")]);
+
+    assert_eq!(set.insert(list_index, 3, String::from("c")), Err(String::from("c")));
+    assert_eq!(set.replace(list_index, 2, String::from("d")), Err(String::from("d")));
+    assert_eq!(
+        set.swap(list_index, 0, 2),
+        Err(ListSetError::IndexOutOfBounds { index: 2, length: 2 })
+    );
+    assert_eq!(
+        set.swap(list_index, 4, 0),
+        Err(ListSetError::IndexOutOfBounds { index: 4, length: 2 })
+    );
+
+    let result: Vec<&str> = set.iter(list_index).map(|item| item.as_str()).collect();
+
+    assert_eq!(result, vec!["a", "b"]);
+
+    set.remove_list(list_index);
+
+    assert!(set.swap(list_index, 0, 1).is_err());
+}
+
+/// Appending a list to another list should move every element of the source 
+/// list to the back of the target list, and leave the source list empty.
+#[test]
+fn test_append() {
+    let mut set = LinkedListSet::new();
+    let list_indices = [set.new_list(), set.new_list(), set.new_list()];
+    set.extend(list_indices[0], vec![1, 2, 3]);
+    set.extend(list_indices[1], vec![4, 5, 6]);
+    set.extend(list_indices[2], vec![7, 8, 9]);
+    let handle = set.push_back(list_indices[1], 10);
+    set.append(list_indices[0], list_indices[1]);
+
+    let expected = vec![1, 2, 3, 4, 5, 6, 10];
+    let result: Vec<usize> = set.iter(list_indices[0]).copied().collect();
+    let result_rev: Vec<usize> = set.iter(list_indices[0]).rev().copied().collect();
+
+    assert_eq!(result, expected);
+    assert_eq!(result_rev, expected.iter().rev().copied().collect::<Vec<usize>>());
+    assert_eq!(set.len(list_indices[0]), expected.len());
+    assert!(set.contains_list(list_indices[1]));
+    assert!(set.list_is_empty(list_indices[1]));
+    assert_eq!(set.get(handle), Some(&10));
+    assert_eq!(set.len(list_indices[2]), 3);
+}
+
+/// Prepending a list to another list should move every element of the 
+/// source list to the front of the target list, and leave the source list 
+/// empty.
+#[test]
+fn test_prepend() {
+    let mut set = LinkedListSet::new();
+    let list_indices = [set.new_list(), set.new_list()];
+    set.extend(list_indices[0], vec![4, 5, 6]);
+    set.extend(list_indices[1], vec![1, 2, 3]);
+    set.prepend(list_indices[0], list_indices[1]);
+    set.push_front(list_indices[0], 0);
+
+    let expected = vec![0, 1, 2, 3, 4, 5, 6];
+    let result: Vec<usize> = set.iter(list_indices[0]).copied().collect();
+    let result_rev: Vec<usize> = set.iter(list_indices[0]).rev().copied().collect();
+
+    assert_eq!(result, expected);
+    assert_eq!(result_rev, expected.iter().rev().copied().collect::<Vec<usize>>());
+    assert!(set.list_is_empty(list_indices[1]));
+}
+
+/// Appending to an empty list, or appending an empty list, should produce
+/// the contents of the other list.
+#[test]
+fn test_append_empty_lists() {
+    let mut set = LinkedListSet::new();
+    let list_indices = [set.new_list(), set.new_list()];
+    set.extend(list_indices[1], vec![1, 2, 3]);
+    set.append(list_indices[0], list_indices[1]);
+    set.append(list_indices[0], list_indices[1]);
+    set.append(list_indices[1], list_indices[0]);
+    let result: Vec<usize> = set.iter(list_indices[1]).copied().collect();
+
+    assert_eq!(result, vec![1, 2, 3]);
+    assert!(set.list_is_empty(list_indices[0]));
+    assert_eq!(set.pop_back(list_indices[1]), Some(3));
+    assert_eq!(set.pop_front(list_indices[1]), Some(1));
+}
+
+/// Appending a list to itself should panic.
+#[test]
+#[should_panic]
+fn test_append_to_itself_panics() {
+    let mut set = LinkedListSet::new();
+    let list_index = set.new_list();
+    set.extend(list_index, vec![1, 2, 3]);
+    set.append(list_index, list_index);
+}
+
+/// Splitting off a list at any position should produce the same lists as 
+/// splitting off a vector at the same position.
+#[test]
+fn test_split_off_matches_vec_split_off() {
+    for at in 0..=7 {
+        let mut set = LinkedListSet::new();
+        let list_index = set.new_list();
+        let mut expected: Vec<usize> = (0..7).collect();
+        set.extend(list_index, expected.iter().copied());
+        let expected_tail = expected.split_off(at);
+        let tail_index = set.split_off(list_index, at);
+        let head: Vec<usize> = set.iter(list_index).copied().collect();
+        let tail: Vec<usize> = set.iter(tail_index).copied().collect();
+        let tail_rev: Vec<usize> = set.iter(tail_index).rev().copied().collect();
+
+        assert_eq!(head, expected);
+        assert_eq!(tail, expected_tail);
+        assert_eq!(tail_rev, expected_tail.iter().rev().copied().collect::<Vec<usize>>());
+        assert_eq!(set.len(list_index), expected.len());
+        assert_eq!(set.len(tail_index), expected_tail.len());
+    }
+}
+
+/// Splitting off a list past its length should panic.
+#[test]
+#[should_panic]
+fn test_split_off_out_of_bounds_panics() {
+    let mut set = LinkedListSet::new();
+    let list_index = set.new_list();
+    set.extend(list_index, vec![1, 2, 3]);
+    set.split_off(list_index, 4);
+}
+
+/// Moving elements between lists should relink the elements without 
+/// changing the items, the node handles, or the number of nodes in the set.
+#[test]
+fn test_move_to_back_and_front_between_lists() {
+    let mut set = LinkedListSet::new();
+    let list_indices = [set.new_list(), set.new_list()];
+    let handles: Vec<NodeHandle> = (0..5)
+        .map(|item| set.push_back(list_indices[0], item))
+        .collect();
+
+    assert_eq!(set.move_to_back(list_indices[0], 4, list_indices[1]), Some(handles[4]));
+    assert_eq!(set.move_to_back(list_indices[0], 0, list_indices[1]), Some(handles[0]));
+    assert_eq!(set.move_to_front(list_indices[0], 1, list_indices[1]), Some(handles[2]));
+    assert_eq!(set.move_to_front(list_indices[0], 2, list_indices[1]), None);
+
+    let source: Vec<usize> = set.iter(list_indices[0]).copied().collect();
+    let target: Vec<usize> = set.iter(list_indices[1]).copied().collect();
+    let target_rev: Vec<usize> = set.iter(list_indices[1]).rev().copied().collect();
+
+    assert_eq!(source, vec![1, 3]);
+    assert_eq!(target, vec![2, 4, 0]);
+    assert_eq!(target_rev, vec![0, 4, 2]);
+    assert_eq!(set.len(list_indices[0]), 2);
+    assert_eq!(set.len(list_indices[1]), 3);
+    assert_eq!(set.node_count(), 5);
+    for (item, handle) in handles.iter().enumerate() {
+        assert_eq!(set.get(*handle), Some(&item));
+    }
+}
+
+/// Moving an element to the back or front of its own list should rotate it
+/// into place.
+#[test]
+fn test_move_within_the_same_list() {
+    let mut set = LinkedListSet::new();
+    let list_index = set.new_list();
+    set.extend(list_index, vec![0, 1, 2, 3]);
+    set.move_to_back(list_index, 0, list_index);
+    set.move_to_front(list_index, 3, list_index);
+    set.move_to_back(list_index, 3, list_index);
+
+    let result: Vec<usize> = set.iter(list_index).copied().collect();
+    let result_rev: Vec<usize> = set.iter(list_index).rev().copied().collect();
+
+    assert_eq!(result, vec![0, 1, 2, 3]);
+    assert_eq!(result_rev, vec![3, 2, 1, 0]);
+
+    let single_index = set.new_list();
+    set.push_back(single_index, 4);
+    set.move_to_front(single_index, 0, single_index);
+
+    assert_eq!(set.front(single_index), Some(&4));
+    assert_eq!(set.back(single_index), Some(&4));
+}
+
+/// Moving an element through its handle should keep the handle valid, and 
+/// moving through a stale handle should do nothing.
+#[test]
+fn test_move_node_to_back_and_front() {
+    let mut set = LinkedListSet::new();
+    let list_indices = [set.new_list(), set.new_list()];
+    let handle1 = set.push_back(list_indices[0], 1);
+    let handle2 = set.push_back(list_indices[0], 2);
+    let handle3 = set.push_back(list_indices[0], 3);
+
+    assert!(set.move_node_to_back(handle2, list_indices[1]));
+    assert!(set.move_node_to_front(handle3, list_indices[1]));
+
+    let target: Vec<usize> = set.iter(list_indices[1]).copied().collect();
+
+    assert_eq!(target, vec![3, 2]);
+    assert_eq!(set.get(handle2), Some(&2));
+
+    set.remove_node(handle1);
+
+    assert!(!set.move_node_to_back(handle1, list_indices[1]));
+    assert!(set.list_is_empty(list_indices[0]));
+    assert_eq!(set.len(list_indices[1]), 2);
+}
+
+/// Retaining elements should remove every element that fails the predicate 
+/// in one pass, without disturbing the other lists in the set.
+#[test]
+fn test_retain_matches_vec_retain() {
+    let mut set = LinkedListSet::new();
+    let list_indices = [set.new_list(), set.new_list(), set.new_list()];
+    let mut expected: Vec<usize> = (0..30).collect();
+    for item in 0..30 {
+        set.push_back(list_indices[0], 100 + item);
+        set.push_back(list_indices[1], item);
+        set.push_back(list_indices[2], 200 + item);
+    }
+    let mut calls = 0;
+    set.retain(list_indices[1], |item| {
+        calls += 1;
+        item % 3 != 1
+    });
+    expected.retain(|item| item % 3 != 1);
+    let result: Vec<usize> = set.iter(list_indices[1]).copied().collect();
+    let result_rev: Vec<usize> = set.iter(list_indices[1]).rev().copied().collect();
+
+    assert_eq!(calls, 30);
+    assert_eq!(result, expected);
+    assert_eq!(result_rev, expected.iter().rev().copied().collect::<Vec<usize>>());
+    assert_eq!(set.len(list_indices[1]), expected.len());
+    assert_eq!(set.node_count(), 60 + expected.len());
+    assert_eq!(
+        set.iter(list_indices[0]).copied().collect::<Vec<usize>>(), 
+        (100..130).collect::<Vec<usize>>()
+    );
+    assert_eq!(
+        set.iter(list_indices[2]).copied().collect::<Vec<usize>>(), 
+        (200..230).collect::<Vec<usize>>()
+    );
+}
+
+/// Retaining elements should work when the removed nodes are the last nodes 
+/// in the underlying storage, so that removing them relocates nothing.
+#[test]
+fn test_retain_removes_nodes_at_end_of_storage() {
+    let mut set = LinkedListSet::new();
+    let list_index = set.new_list();
+    set.extend(list_index, vec![1, 2, 3, 4, 5, 6]);
+    set.retain(list_index, |item| *item <= 2);
+    let result: Vec<usize> = set.iter(list_index).copied().collect();
+
+    assert_eq!(result, vec![1, 2]);
+
+    set.retain(list_index, |_| false);
+
+    assert!(set.list_is_empty(list_index));
+    assert_eq!(set.node_count(), 0);
+}
+
+/// Retaining elements with a mutable predicate should keep the updates to the 
+/// retained elements, and keep their node handles valid.
+#[test]
+fn test_retain_mut() {
+    let mut set = LinkedListSet::new();
+    let list_index = set.new_list();
+    let handles: Vec<NodeHandle> = (0..6)
+        .map(|item| set.push_back(list_index, item))
+        .collect();
+    set.retain_mut(list_index, |item| {
+        *item += 10;
+        *item % 2 == 1
+    });
+    let result: Vec<usize> = set.iter(list_index).copied().collect();
+
+    assert_eq!(result, vec![11, 13, 15]);
+    assert_eq!(set.get(handles[1]), Some(&11));
+    assert_eq!(set.get(handles[5]), Some(&15));
+    assert_eq!(set.get(handles[0]), None);
+}
+
+/// Extracting elements should yield the matching elements in list order, and 
+/// leave the elements the iterator has not reached in the list.
+#[test]
+fn test_extract_if_is_lazy() {
+    let mut set = LinkedListSet::new();
+    let list_indices = [set.new_list(), set.new_list()];
+    set.extend(list_indices[0], 0..10);
+    set.extend(list_indices[1], 10..20);
+    {
+        let mut extracted = set.extract_if(list_indices[0], |item| *item % 2 == 0);
+
+        assert_eq!(extracted.next(), Some(0));
+        assert_eq!(extracted.next(), Some(2));
+    }
+    let result: Vec<usize> = set.iter(list_indices[0]).copied().collect();
+
+    assert_eq!(result, vec![1, 3, 4, 5, 6, 7, 8, 9]);
+
+    let extracted: Vec<usize> = set.extract_if(list_indices[0], |item| *item > 4).collect();
+    let result: Vec<usize> = set.iter(list_indices[0]).copied().collect();
+    let other: Vec<usize> = set.iter(list_indices[1]).copied().collect();
+
+    assert_eq!(extracted, vec![5, 6, 7, 8, 9]);
+    assert_eq!(result, vec![1, 3, 4]);
+    assert_eq!(other, (10..20).collect::<Vec<usize>>());
+}
+
+/// Draining a list should yield its elements in list order from either end, 
+/// and leave the list empty but in the set.
+#[test]
+fn test_drain_double_ended() {
+    let mut set = LinkedListSet::new();
+    let list_indices = [set.new_list(), set.new_list()];
+    set.extend(list_indices[0], 0..6);
+    set.extend(list_indices[1], 10..16);
+    let result: Vec<usize> = {
+        let mut drain = set.drain(list_indices[0]);
+        let mut result = vec![drain.next().unwrap(), drain.next_back().unwrap()];
+
+        assert_eq!(drain.len(), 4);
+
+        result.extend(drain);
+        result
+    };
+
+    assert_eq!(result, vec![0, 5, 1, 2, 3, 4]);
+    assert!(set.contains_list(list_indices[0]));
+    assert!(set.list_is_empty(list_indices[0]));
+    assert_eq!(
+        set.iter(list_indices[1]).copied().collect::<Vec<usize>>(), 
+        (10..16).collect::<Vec<usize>>()
+    );
+}
+
+/// Dropping a draining iterator early should still empty the list.
+#[test]
+fn test_drain_dropped_early_empties_list() {
+    let mut set = LinkedListSet::new();
+    let list_indices = [set.new_list(), set.new_list()];
+    set.extend(list_indices[0], 0..6);
+    set.extend(list_indices[1], 10..16);
+    {
+        let mut drain = set.drain(list_indices[0]);
+
+        assert_eq!(drain.next(), Some(0));
+    }
+
+    assert!(set.list_is_empty(list_indices[0]));
+    assert_eq!(set.node_count(), 6);
+
+    set.push_back(list_indices[0], 100);
+
+    assert_eq!(set.front(list_indices[0]), Some(&100));
+}
+
+/// Draining a whole set should yield every element with the index of its 
+/// list, and leave every list empty but in the set.
+#[test]
+fn test_drain_all() {
+    let mut set = LinkedListSet::new();
+    let list_indices = [set.new_list(), set.new_list(), set.new_list()];
+    let mut expected = Vec::new();
+    for item in 0..12 {
+        let list_index = list_indices[item % 3];
+        set.push_back(list_index, item);
+        expected.push((list_index, item));
+    }
+    let handle = set.push_front(list_indices[0], 12);
+    expected.push((list_indices[0], 12));
+    let capacity = set.node_capacity();
+    let mut result: Vec<(ListIndex, usize)> = set.drain_all().collect();
+    result.sort_by_key(|(_, item)| *item);
+    expected.sort_by_key(|(_, item)| *item);
+
+    assert_eq!(result, expected);
+    assert_eq!(set.node_count(), 0);
+    assert_eq!(set.node_capacity(), capacity);
+    assert_eq!(set.get(handle), None);
+    for list_index in list_indices.iter().copied() {
+        assert!(set.contains_list(list_index));
+        assert!(set.list_is_empty(list_index));
+    }
+
+    set.push_back(list_indices[1], 1);
+
+    assert_eq!(set.len(list_indices[1]), 1);
+}
+
+/// Removing a list into a vector should return its elements in list order, 
+/// and remove the list from the set.
+#[test]
+fn test_remove_list_into_vec() {
+    let mut set = LinkedListSet::new();
+    let list_indices = [set.new_list(), set.new_list()];
+    set.extend(list_indices[0], vec![3, 1, 2]);
+    set.extend(list_indices[1], vec![4, 5]);
+    set.push_front(list_indices[0], 0);
+
+    assert_eq!(set.remove_list_into_vec(list_indices[0]), Some(vec![0, 3, 1, 2]));
+    assert!(!set.contains_list(list_indices[0]));
+    assert_eq!(set.remove_list_into_vec(list_indices[0]), None);
+    assert_eq!(set.node_count(), 2);
+    assert_eq!(set.iter(list_indices[1]).copied().collect::<Vec<usize>>(), vec![4, 5]);
+}
+
+/// Dropping the draining iterator of a removed list early should still 
+/// remove the list and all its elements.
+#[test]
+fn test_remove_list_drain_dropped_early() {
+    let mut set = LinkedListSet::new();
+    let list_index = set.new_list();
+    set.extend(list_index, vec![1, 2, 3]);
+    {
+        let mut drain = set.remove_list_drain(list_index).unwrap();
+
+        assert_eq!(drain.next_back(), Some(3));
+    }
+
+    assert!(!set.contains_list(list_index));
+    assert_eq!(set.node_count(), 0);
+    assert!(set.remove_list_drain(list_index).is_none());
+}
+
+/// Sorting a list should produce the same order as a stable sort of a 
+/// vector, and keep the node handles pointing to the same items.
+#[test]
+fn test_sort_by_key_matches_vec_stable_sort() {
+    for length in 0..40 {
+        let mut set = LinkedListSet::new();
+        let list_indices = [set.new_list(), set.new_list()];
+        let mut expected: Vec<(usize, usize)> = (0..length)
+            .map(|i| ((i * 7919) % 11, i))
+            .collect();
+        let mut handles = Vec::new();
+        for item in expected.iter().copied() {
+            set.push_back(list_indices[1], (0, item.1));
+            handles.push(set.push_back(list_indices[0], item));
+        }
+        set.sort_by_key(list_indices[0], |item| item.0);
+        expected.sort_by_key(|item| item.0);
+        let result: Vec<(usize, usize)> = set.iter(list_indices[0]).copied().collect();
+        let result_rev: Vec<(usize, usize)> = set.iter(list_indices[0]).rev().copied().collect();
+
+        assert_eq!(result, expected);
+        assert_eq!(result_rev, expected.iter().rev().copied().collect::<Vec<(usize, usize)>>());
+        assert_eq!(set.len(list_indices[0]), length);
+        assert_eq!(set.front(list_indices[0]), expected.first());
+        assert_eq!(set.back(list_indices[0]), expected.last());
+        assert_eq!(
+            set.iter(list_indices[1]).map(|item| item.1).collect::<Vec<usize>>(), 
+            (0..length).collect::<Vec<usize>>()
+        );
+        for (i, handle) in handles.iter().enumerate() {
+            assert_eq!(set.get(*handle).map(|item| item.1), Some(i));
+        }
+    }
+}
+
+/// A comparison that panics part way through a sort should leave every item
+/// in the list exactly once, reachable from either end.
+#[test]
+fn test_sort_by_panic_leaves_list_intact() {
+    for items in [vec![3, 1, 0, 2], vec![3, 1, 0, 2, 7, 5, 6, 4, 8]] {
+        for panic_at in 1..20 {
+            let mut set = LinkedListSet::new();
+            let list_index = set.new_list();
+            set.extend(list_index, items.iter().copied());
+            let mut comparisons = 0;
+            let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                set.sort_by(list_index, |a, b| {
+                    comparisons += 1;
+                    if comparisons == panic_at {
+                        panic!("comparison {}", comparisons);
+                    }
+
+                    a.cmp(b)
+                });
+            }));
+            let mut result_items: Vec<usize> = set.iter_mut(list_index)
+                .map(|item| *item)
+                .collect();
+            let mut result_items_rev: Vec<usize> = set.iter(list_index)
+                .rev()
+                .copied()
+                .collect();
+            result_items_rev.reverse();
+
+            assert_eq!(result.is_err(), comparisons == panic_at);
+            assert_eq!(result_items, result_items_rev);
+            assert_eq!(set.len(list_index), items.len());
+
+            result_items.sort();
+
+            assert_eq!(result_items, (0..items.len()).collect::<Vec<usize>>());
+        }
+    }
+}
+
+/// Sorting a list should leave the list sorted, and a list should be sorted 
+/// after sorting it in either direction.
+#[test]
+fn test_sort_and_is_sorted() {
+    let mut set = LinkedListSet::new();
+    let list_index = set.new_list();
+    set.extend(list_index, vec![9, 1, 8, 2, 7, 3, 6, 4, 5, 0]);
+
+    assert!(!set.is_sorted(list_index));
+
+    set.sort(list_index);
+
+    assert!(set.is_sorted(list_index));
+    assert_eq!(
+        set.iter(list_index).copied().collect::<Vec<usize>>(), 
+        (0..10).collect::<Vec<usize>>()
+    );
+
+    set.sort_unstable_by(list_index, |a, b| b.cmp(a));
+
+    assert!(!set.is_sorted(list_index));
+    assert_eq!(
+        set.iter(list_index).copied().collect::<Vec<usize>>(), 
+        (0..10).rev().collect::<Vec<usize>>()
+    );
+
+    set.push_back(list_index, 100);
+    set.sort_by(list_index, |a, b| a.cmp(b));
+
+    assert_eq!(set.back(list_index), Some(&100));
+    assert_eq!(set.pop_front(list_index), Some(0));
+}
+
+/// Inserting items into a sorted list should keep the list sorted, and 
+/// place each item after the elements that compare equal to it.
+#[test]
+fn test_insert_sorted_is_stable() {
+    let mut set = LinkedListSet::new();
+    let list_index = set.new_list();
+    let mut expected = Vec::new();
+    for i in 0..30 {
+        let item = ((i * 13) % 7, i);
+        set.insert_sorted_by(list_index, item, |a, b| a.0.cmp(&b.0));
+        expected.push(item);
+    }
+    expected.sort_by_key(|item| item.0);
+    let result: Vec<(usize, usize)> = set.iter(list_index).copied().collect();
+    let result_rev: Vec<(usize, usize)> = set.iter(list_index).rev().copied().collect();
+
+    assert_eq!(result, expected);
+    assert_eq!(result_rev, expected.iter().rev().copied().collect::<Vec<(usize, usize)>>());
+}
+
+/// Merging two sorted lists should produce one sorted list, leave the source 
+/// list empty, and keep the node handles of the merged elements valid.
+#[test]
+fn test_merge_sorted() {
+    let mut set = LinkedListSet::new();
+    let list_indices = [set.new_list(), set.new_list(), set.new_list()];
+    set.extend(list_indices[0], vec![2, 4, 4, 6, 8]);
+    let handles: Vec<NodeHandle> = vec![0, 1, 4, 9, 10]
+        .into_iter()
+        .map(|item| set.push_back(list_indices[1], item))
+        .collect();
+    set.extend(list_indices[2], vec![100]);
+    set.merge_sorted(list_indices[0], list_indices[1]);
+
+    let expected = vec![0, 1, 2, 4, 4, 4, 6, 8, 9, 10];
+    let result: Vec<usize> = set.iter(list_indices[0]).copied().collect();
+    let result_rev: Vec<usize> = set.iter(list_indices[0]).rev().copied().collect();
+
+    assert_eq!(result, expected);
+    assert_eq!(result_rev, expected.iter().rev().copied().collect::<Vec<usize>>());
+    assert_eq!(set.len(list_indices[0]), expected.len());
+    assert!(set.list_is_empty(list_indices[1]));
+    assert_eq!(set.get(handles[3]), Some(&9));
+    assert_eq!(set.len(list_indices[2]), 1);
+
+    set.merge_sorted(list_indices[1], list_indices[0]);
+
+    assert_eq!(set.iter(list_indices[1]).copied().collect::<Vec<usize>>(), expected);
+    assert!(set.list_is_empty(list_indices[0]));
+}
+
+/// A comparison that panics part way through a merge should leave each 
+/// element in exactly one of the two lists, so that borrowing both lists 
+/// mutably never hands out the same element twice.
+#[test]
+fn test_merge_sorted_by_panic_leaves_lists_disjoint() {
+    let mut set = LinkedListSet::new();
+    let target_index = set.new_list();
+    let source_index = set.new_list();
+    set.extend(target_index, vec![10]);
+    set.extend(source_index, vec![1, 2]);
+    let mut comparisons = 0;
+    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+        set.merge_sorted_by(target_index, source_index, |a, b| {
+            comparisons += 1;
+            if comparisons == 2 {
+                panic!("comparison {}", comparisons);
+            }
+
+            a.cmp(b)
+        });
+    }));
+
+    assert!(result.is_err());
+
+    let [target, source] = set.get_disjoint_mut([target_index, source_index]).unwrap();
+    let mut items: Vec<usize> = target.chain(source).map(|item| *item).collect();
+    items.sort();
+
+    assert_eq!(items, vec![1, 2, 10]);
+    assert_eq!(set.iter(target_index).copied().collect::<Vec<usize>>(), vec![1, 10]);
+    assert_eq!(set.iter(target_index).rev().copied().collect::<Vec<usize>>(), vec![10, 1]);
+    assert_eq!(set.iter(source_index).copied().collect::<Vec<usize>>(), vec![2]);
+    assert_eq!(set.iter(source_index).rev().copied().collect::<Vec<usize>>(), vec![2]);
+    assert_eq!(set.len(target_index) + set.len(source_index), 3);
+}
+
+/// Removing the duplicates from a sorted list should keep one element of 
+/// each run of equal elements.
+#[test]
+fn test_dedup_sorted() {
+    let mut set = LinkedListSet::new();
+    let list_indices = [set.new_list(), set.new_list()];
+    set.extend(list_indices[0], vec![0, 0, 0, 1, 2, 2, 3, 3, 3, 3]);
+    set.extend(list_indices[1], vec![5, 5, 5]);
+    set.dedup_sorted(list_indices[0]);
+    set.dedup_sorted(list_indices[1]);
+    let result: Vec<usize> = set.iter(list_indices[0]).copied().collect();
+    let result_rev: Vec<usize> = set.iter(list_indices[0]).rev().copied().collect();
+
+    assert_eq!(result, vec![0, 1, 2, 3]);
+    assert_eq!(result_rev, vec![3, 2, 1, 0]);
+    assert_eq!(set.iter(list_indices[1]).copied().collect::<Vec<usize>>(), vec![5]);
+    assert_eq!(set.node_count(), 5);
+}
+
+/// Removing consecutive duplicates should match removing consecutive 
+/// duplicates from a vector.
+#[test]
+fn test_dedup_matches_vec_dedup() {
+    let mut set = LinkedListSet::new();
+    let list_indices = [set.new_list(), set.new_list()];
+    let mut expected: Vec<usize> = (0..60).map(|i| (i * i / 7) % 5).collect();
+    for item in expected.iter().copied() {
+        set.push_back(list_indices[0], item);
+        set.push_back(list_indices[1], item);
+    }
+    set.dedup(list_indices[0]);
+    expected.dedup();
+    let result: Vec<usize> = set.iter(list_indices[0]).copied().collect();
+    let result_rev: Vec<usize> = set.iter(list_indices[0]).rev().copied().collect();
+
+    assert_eq!(result, expected);
+    assert_eq!(result_rev, expected.iter().rev().copied().collect::<Vec<usize>>());
+    assert_eq!(set.len(list_indices[1]), 60);
+    assert_eq!(set.node_count(), 60 + expected.len());
+}
+
+/// Removing consecutive duplicates with a comparison function should compare
+/// each element against the last element kept, as `Vec::dedup_by` does.
+#[test]
+fn test_dedup_by_and_dedup_by_key_match_vec() {
+    let items: Vec<usize> = vec![1, 2, 3, 7, 8, 9, 15, 16, 30, 31, 32, 33];
+    let mut set = LinkedListSet::new();
+    let list_indices = [set.new_list(), set.new_list()];
+    set.extend(list_indices[0], items.iter().copied());
+    set.extend(list_indices[1], items.iter().copied());
+    set.dedup_by(list_indices[0], |a, b| *a - *b < 2);
+    set.dedup_by_key(list_indices[1], |item| *item / 10);
+    let mut expected_by = items.clone();
+    expected_by.dedup_by(|a, b| *a - *b < 2);
+    let mut expected_by_key = items.clone();
+    expected_by_key.dedup_by_key(|item| *item / 10);
+
+    assert_eq!(set.iter(list_indices[0]).copied().collect::<Vec<usize>>(), expected_by);
+    assert_eq!(set.iter(list_indices[1]).copied().collect::<Vec<usize>>(), expected_by_key);
+}
+
+/// Removing every duplicate from a list should keep the first occurrence of 
+/// each element in order, and keep the node handles of the kept elements 
+/// valid.
+#[test]
+fn test_dedup_all_keeps_first_occurrences() {
+    let mut set = LinkedListSet::new();
+    let list_indices = [set.new_list(), set.new_list()];
+    let items: Vec<usize> = (0..50).map(|i| (i * 7) % 11).collect();
+    let mut handles = Vec::new();
+    for item in items.iter().copied() {
+        set.push_back(list_indices[1], item);
+        handles.push(set.push_back(list_indices[0], item));
+    }
+    set.dedup_all(list_indices[0]);
+    let mut expected = Vec::new();
+    for item in items.iter().copied() {
+        if !expected.contains(&item) {
+            expected.push(item);
+        }
+    }
+    let result: Vec<usize> = set.iter(list_indices[0]).copied().collect();
+    let result_rev: Vec<usize> = set.iter(list_indices[0]).rev().copied().collect();
+
+    assert_eq!(result, expected);
+    assert_eq!(result_rev, expected.iter().rev().copied().collect::<Vec<usize>>());
+    assert_eq!(set.iter(list_indices[1]).copied().collect::<Vec<usize>>(), items);
+    for (i, handle) in handles.iter().enumerate() {
+        if i < expected.len() {
+            assert_eq!(set.get(*handle), Some(&items[i]));
+        } else {
+            assert_eq!(set.get(*handle), None);
+        }
+    }
+}
+
+/// Reversing a list should reverse its elements in both directions of 
+/// iteration, and keep the node handles pointing to the same items.
+#[test]
+fn test_reverse() {
+    for length in 0..6 {
+        let mut set = LinkedListSet::new();
+        let list_indices = [set.new_list(), set.new_list()];
+        set.extend(list_indices[1], 0..3);
+        let handles: Vec<NodeHandle> = (0..length)
+            .map(|item| set.push_back(list_indices[0], item))
+            .collect();
+        set.reverse(list_indices[0]);
+        let expected: Vec<usize> = (0..length).rev().collect();
+        let result: Vec<usize> = set.iter(list_indices[0]).copied().collect();
+        let result_rev: Vec<usize> = set.iter(list_indices[0]).rev().copied().collect();
+
+        assert_eq!(result, expected);
+        assert_eq!(result_rev, (0..length).collect::<Vec<usize>>());
+        assert_eq!(set.front(list_indices[0]), expected.first());
+        assert_eq!(set.back(list_indices[0]), expected.last());
+        assert_eq!(set.iter(list_indices[1]).copied().collect::<Vec<usize>>(), vec![0, 1, 2]);
+        for (item, handle) in handles.iter().enumerate() {
+            assert_eq!(set.get(*handle), Some(&item));
+        }
+
+        set.push_back(list_indices[0], 100);
+
+        assert_eq!(set.back(list_indices[0]), Some(&100));
+    }
+}
+
+/// Rotating a list should match rotating a vector by the same amount in 
+/// either direction.
+#[test]
+fn test_rotate_matches_vec_rotate() {
+    for length in 0..8 {
+        for k in 0..=length {
+            let mut set = LinkedListSet::new();
+            let list_indices = [set.new_list(), set.new_list()];
+            set.extend(list_indices[0], 0..length);
+            set.extend(list_indices[1], 0..length);
+            set.rotate_left(list_indices[0], k);
+            set.rotate_right(list_indices[1], k);
+            let mut expected_left: Vec<usize> = (0..length).collect();
+            expected_left.rotate_left(k);
+            let mut expected_right: Vec<usize> = (0..length).collect();
+            expected_right.rotate_right(k);
+            let result_left: Vec<usize> = set.iter(list_indices[0]).copied().collect();
+            let result_right_rev: Vec<usize> = set.iter(list_indices[1]).rev().copied().collect();
+
+            assert_eq!(result_left, expected_left);
+            assert_eq!(result_right_rev, expected_right.iter().rev().copied().collect::<Vec<usize>>());
+            assert_eq!(set.len(list_indices[0]), length);
+        }
+    }
+}
+
+/// Rotating a list by more than its length should panic.
+#[test]
+#[should_panic]
+fn test_rotate_left_past_length_panics() {
+    let mut set = LinkedListSet::new();
+    let list_index = set.new_list();
+    set.extend(list_index, vec![1, 2, 3]);
+    set.rotate_left(list_index, 4);
+}
+
+/// Alternating between the front and the back of an iterator should yield 
+/// every element exactly once, and stop where the two ends meet.
+#[test]
+fn test_iter_mixed_directions_yields_each_element_once() {
+    for length in 0..8 {
+        let mut set = LinkedListSet::new();
+        let list_indices = [set.new_list(), set.new_list()];
+        set.extend(list_indices[0], 0..length);
+        set.extend(list_indices[1], 100..110);
+        let mut iter = set.iter(list_indices[0]);
+        let mut front = Vec::new();
+        let mut back = Vec::new();
+        for step in 0..(2 * length + 2) {
+            assert_eq!(iter.len(), length - front.len() - back.len());
+
+            let item = if step % 2 == 0 { iter.next() } else { iter.next_back() };
+            match item {
+                Some(item) if step % 2 == 0 => front.push(*item),
+                Some(item) => back.push(*item),
+                None => {}
+            }
+        }
+        back.reverse();
+        front.extend(back);
+
+        assert_eq!(front, (0..length).collect::<Vec<usize>>());
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+}
+
+/// Alternating between the front and the back of a mutable iterator should 
+/// visit every element exactly once.
+#[test]
+fn test_iter_mut_mixed_directions_visits_each_element_once() {
+    for length in 0..8 {
+        let mut set = LinkedListSet::new();
+        let list_indices = [set.new_list(), set.new_list()];
+        set.extend(list_indices[0], vec![0; length]);
+        set.extend(list_indices[1], vec![0; 3]);
+        let mut iter = set.iter_mut(list_indices[0]);
+        let mut visited = 0;
+        for step in 0..(2 * length + 2) {
+            let item = if step % 2 == 0 { iter.next() } else { iter.next_back() };
+            if let Some(item) = item {
+                *item += 1;
+                visited += 1;
+            }
+
+            assert_eq!(iter.size_hint(), (length - visited, Some(length - visited)));
+        }
+
+        assert_eq!(visited, length);
+        assert!(set.iter(list_indices[0]).all(|item| *item == 1));
+        assert!(set.iter(list_indices[1]).all(|item| *item == 0));
+    }
+}
+
+/// Zipping a forward iterator with a reversed iterator over the same list 
+/// should pair each element with its mirror image, and reversing a 
+/// partially consumed iterator should yield only the unconsumed elements.
+#[test]
+fn test_iter_zip_and_rev() {
+    let mut set = LinkedListSet::new();
+    let list_index = set.new_list();
+    set.extend(list_index, 0..6);
+    let pairs: Vec<(usize, usize)> = set.iter(list_index)
+        .zip(set.iter(list_index).rev())
+        .map(|(a, b)| (*a, *b))
+        .collect();
+
+    assert_eq!(pairs, vec![(0, 5), (1, 4), (2, 3), (3, 2), (4, 1), (5, 0)]);
+
+    let mut iter = set.iter(list_index);
+    iter.next();
+    iter.next_back();
+    let rest: Vec<usize> = iter.rev().copied().collect();
+
+    assert_eq!(rest, vec![4, 3, 2, 1]);
+
+    for (item, other) in set.iter_mut(list_index).rev().zip(10..) {
+        *item += other;
+    }
+    let result: Vec<usize> = set.iter(list_index).copied().collect();
+
+    assert_eq!(result, vec![15, 15, 15, 15, 15, 15]);
+}
+
+/// An owning iterator over a list should yield the elements of the list in 
+/// list order from either end.
+#[test]
+fn test_into_list_iter() {
+    let mut set = LinkedListSet::new();
+    let list_indices = [set.new_list(), set.new_list()];
+    set.extend(list_indices[0], (0..5).map(|i| i.to_string()));
+    set.extend(list_indices[1], (5..10).map(|i| i.to_string()));
+    set.push_front(list_indices[0], String::from("front"));
+    let mut iter = set.into_list_iter(list_indices[0]);
+
+    assert_eq!(iter.len(), 6);
+    assert_eq!(iter.next(), Some(String::from("front")));
+    assert_eq!(iter.next_back(), Some(String::from("4")));
+    assert_eq!(iter.collect::<Vec<String>>(), vec!["0", "1", "2", "3"]);
+}
+
+/// Consuming a set should yield every element with the index of its list, 
+/// list by list, with each list in list order.
+#[test]
+fn test_into_iter_yields_lists_in_order() {
+    let mut set = LinkedListSet::new();
+    let list_indices = [set.new_list(), set.new_list(), set.new_list()];
+    set.extend(list_indices[2], vec![7, 8]);
+    set.extend(list_indices[0], vec![1, 2, 3]);
+    set.push_front(list_indices[0], 0);
+    set.remove_list(list_indices[1]);
+    let iter = set.into_iter();
+
+    assert_eq!(iter.len(), 6);
+
+    let result: Vec<(ListIndex, usize)> = iter.collect();
+    let expected = vec![
+        (list_indices[0], 0),
+        (list_indices[0], 1),
+        (list_indices[0], 2),
+        (list_indices[0], 3),
+        (list_indices[2], 7),
+        (list_indices[2], 8),
+    ];
+
+    assert_eq!(result, expected);
+}
+
+/// Converting a set into vectors should produce one vector per list, 
+/// including the empty lists, with each list in list order.
+#[test]
+fn test_into_vecs() {
+    let mut set = LinkedListSet::new();
+    let list_indices = [set.new_list(), set.new_list(), set.new_list()];
+    for item in 0..9 {
+        set.push_back(list_indices[item % 3], item);
+    }
+    set.clear(list_indices[1]);
+    set.reverse(list_indices[2]);
+    let result = set.into_vecs();
+    let expected = vec![
+        (list_indices[0], vec![0, 3, 6]),
+        (list_indices[1], vec![]),
+        (list_indices[2], vec![8, 5, 2]),
+    ];
+
+    assert_eq!(result, expected);
+}
+
+/// Iterating over the nodes of a set should visit every element of every 
+/// list exactly once, paired with the index of its list.
+#[test]
+fn test_nodes_visits_every_element_once() {
+    let mut set = LinkedListSet::new();
+    let list_indices = [set.new_list(), set.new_list(), set.new_list()];
+    for item in 0..20 {
+        let list_index = list_indices[(item * 7) % 3];
+        if item % 2 == 0 {
+            set.push_back(list_index, item);
+        } else {
+            set.push_front(list_index, item);
+        }
+    }
+    set.remove(list_indices[1], 2);
+    let mut expected: Vec<(ListIndex, usize)> = list_indices.iter()
+        .flat_map(|list_index| set.iter(*list_index).map(move |item| (*list_index, *item)))
+        .collect();
+    let mut result: Vec<(ListIndex, usize)> = set.nodes().map(|(list_index, item)| (list_index, *item)).collect();
+    expected.sort_by_key(|(_, item)| *item);
+    result.sort_by_key(|(_, item)| *item);
+
+    assert_eq!(set.nodes().len(), set.node_count());
+    assert_eq!(result, expected);
+}
+
+/// Mutating the elements of a set in storage order should update every 
+/// element of every list.
+#[test]
+fn test_nodes_mut_for_each_mut_and_map_in_place() {
+    let mut set = LinkedListSet::new();
+    let list_indices = [set.new_list(), set.new_list()];
+    set.extend(list_indices[0], 0..5);
+    set.extend(list_indices[1], 10..15);
+    for (list_index, item) in set.nodes_mut() {
+        if list_index == list_indices[0] {
+            *item += 100;
+        }
+    }
+    set.for_each_mut(|item| *item *= 2);
+    set.map_in_place(|item| item + 1);
+
+    assert_eq!(
+        set.iter(list_indices[0]).copied().collect::<Vec<usize>>(), 
+        vec![201, 203, 205, 207, 209]
+    );
+    assert_eq!(
+        set.iter(list_indices[1]).copied().collect::<Vec<usize>>(), 
+        vec![21, 23, 25, 27, 29]
+    );
+}
+
+/// Iterating over the lists of a set should yield every list in the same 
+/// order as the list indices, each with the elements of that list.
+#[test]
+fn test_iter_lists_matches_list_indices() {
+    let mut set = LinkedListSet::new();
+    let list_indices = [set.new_list(), set.new_list(), set.new_list(), set.new_list()];
+    for (i, list_index) in list_indices.iter().copied().enumerate() {
+        set.extend(list_index, (0..i).map(|item| 10 * i + item));
+    }
+    set.remove_list(list_indices[1]);
+    let iter = set.iter_lists();
+
+    assert_eq!(iter.len(), 3);
+
+    let result: Vec<(ListIndex, Vec<usize>)> = iter
+        .map(|(list_index, iter)| (list_index, iter.copied().collect()))
+        .collect();
+    let expected: Vec<(ListIndex, Vec<usize>)> = set.list_indices()
+        .map(|list_index| (list_index, set.iter(list_index).copied().collect()))
+        .collect();
+
+    assert_eq!(result, expected);
+    assert_eq!(result[2], (list_indices[3], vec![30, 31, 32]));
+}
+
+/// Mutable iterators over every list in a set should be usable at the same 
+/// time.
+#[test]
+fn test_iter_lists_mut_iterators_coexist() {
+    let mut set = LinkedListSet::new();
+    let list_indices = [set.new_list(), set.new_list(), set.new_list()];
+    for item in 0..12 {
+        set.push_back(list_indices[item % 3], item);
+    }
+    let mut iters: Vec<(ListIndex, ListIterMut<usize>)> = set.iter_lists_mut().collect();
+    let (_, mut first) = iters.remove(0);
+    for (_, iter) in iters.iter_mut() {
+        for (item, first_item) in iter.zip(first.by_ref()) {
+            *item += 100;
+            *first_item += 1000;
+        }
+    }
+    drop(iters);
+
+    assert_eq!(set.iter(list_indices[0]).copied().collect::<Vec<usize>>(), vec![1000, 1003, 1006, 1009]);
+    assert_eq!(set.iter(list_indices[1]).copied().collect::<Vec<usize>>(), vec![101, 104, 107, 110]);
+    assert_eq!(set.iter(list_indices[2]).copied().collect::<Vec<usize>>(), vec![2, 5, 8, 11]);
+}
+
+/// Requesting mutable iterators over several lists should fail if any list 
+/// appears twice, or any list index does not point to a list in the set.
+#[test]
+fn test_get_disjoint_mut_rejects_duplicates_and_stale_lists() {
+    let mut set = LinkedListSet::new();
+    let list_indices = [set.new_list(), set.new_list(), set.new_list()];
+    set.extend(list_indices[0], vec![1, 2]);
+    set.extend(list_indices[1], vec![3, 4]);
+    set.remove_list(list_indices[2]);
+
+    assert_eq!(
+        set.get_disjoint_mut([list_indices[0], list_indices[1], list_indices[0]]).err(),
+        Some(ListSetError::DuplicateList(list_indices[0]))
+    );
+    assert_eq!(
+        set.get_disjoint_mut([list_indices[0], list_indices[2]]).err(),
+        Some(ListSetError::StaleHandle(list_indices[2]))
+    );
+    assert_eq!(
+        set.iter_mut_many(&[list_indices[1], list_indices[1]]).err(),
+        Some(ListSetError::DuplicateList(list_indices[1]))
+    );
+
+    let [a, b] = set.get_disjoint_mut([list_indices[1], list_indices[0]]).unwrap();
+    for (a, b) in a.rev().zip(b) {
+        *a += *b;
+    }
+
+    assert_eq!(set.iter(list_indices[1]).copied().collect::<Vec<usize>>(), vec![5, 5]);
+}
+
+/// Requesting mutable iterators over no lists should succeed trivially.
+#[test]
+fn test_iter_mut_many_empty() {
+    let mut set: LinkedListSet<usize> = LinkedListSet::new();
+    let iters = set.iter_mut_many(&[]).unwrap();
+
+    assert!(iters.is_empty());
+}
+
+/// Compacting a set should lay out the elements of each list contiguously
+/// in list order, without changing the contents of any list or invalidating
+/// any node handles.
+#[test]
+fn test_compact_lays_out_lists_contiguously() {
+    let mut set = LinkedListSet::new();
+    let list_indices = [set.new_list(), set.new_list(), set.new_list(), set.new_list()];
+    let mut handles = Vec::new();
+    for item in 0..40 {
+        let list_index = list_indices[(item * 5) % 4];
+        let handle = if item % 3 == 0 {
+            set.push_front(list_index, item)
+        } else {
+            set.push_back(list_index, item)
+        };
+        handles.push(handle);
+    }
+    for item in (0..40).step_by(7) {
+        set.remove_node(handles[item]);
+    }
+    set.remove_list(list_indices[2]);
+    let expected: Vec<(ListIndex, Vec<usize>)> = set.list_indices()
+        .map(|list_index| (list_index, set.iter(list_index).copied().collect()))
+        .collect();
+    set.compact();
+    let result: Vec<(ListIndex, Vec<usize>)> = set.list_indices()
+        .map(|list_index| (list_index, set.iter(list_index).copied().collect()))
+        .collect();
+    let storage_order: Vec<usize> = set.nodes().map(|(_, item)| *item).collect();
+    let expected_storage_order: Vec<usize> = expected.iter()
+        .flat_map(|(_, items)| items.iter().copied())
+        .collect();
+
+    assert_eq!(result, expected);
+    assert_eq!(storage_order, expected_storage_order);
+    for (item, handle) in handles.iter().enumerate() {
+        if set.contains_node(*handle) {
+            assert_eq!(set.get(*handle), Some(&item));
+        }
+    }
+    for (list_index, items) in expected.iter() {
+        let result_rev: Vec<usize> = set.iter(*list_index).rev().copied().collect();
+
+        assert_eq!(result_rev, items.iter().rev().copied().collect::<Vec<usize>>());
+    }
+}
+
+/// Defragmenting a set a few nodes at a time should eventually produce the 
+/// same layout as compacting it in one go.
+#[test]
+fn test_defragment_matches_compact() {
+    let mut set = LinkedListSet::new();
+    let list_indices = [set.new_list(), set.new_list(), set.new_list()];
+    for item in 0..30 {
+        set.push_back(list_indices[(item * 7) % 3], item);
+    }
+    let mut compacted = set.clone();
+    compacted.compact();
+    let mut calls = 0;
+    while !set.defragment(4) {
+        calls += 1;
+    }
+    let storage_order: Vec<(usize, usize)> = set.nodes()
+        .map(|(list_index, item)| (set.list_indices().position(|other| other == list_index).unwrap(), *item))
+        .collect();
+    let expected_storage_order: Vec<(usize, usize)> = compacted.nodes()
+        .map(|(list_index, item)| (compacted.list_indices().position(|other| other == list_index).unwrap(), *item))
+        .collect();
+
+    assert!(calls >= 30 / 4);
+    assert_eq!(storage_order, expected_storage_order);
+}
+
+/// Modifying a set in the middle of an incremental defragmentation pass 
+/// should leave every list intact, and the next pass should finish the 
+/// compaction.
+#[test]
+fn test_defragment_with_modifications_between_steps() {
+    let mut set = LinkedListSet::new();
+    let list_indices = [set.new_list(), set.new_list(), set.new_list()];
+    let mut expected: Vec<Vec<usize>> = vec![Vec::new(); 3];
+    for item in 0..30 {
+        set.push_back(list_indices[item % 3], item);
+        expected[item % 3].push(item);
+    }
+    let mut step = 0;
+    while !set.defragment(3) {
+        step += 1;
+        match step % 4 {
+            0 => {
+                set.push_front(list_indices[1], 100 + step);
+                expected[1].insert(0, 100 + step);
+            }
+            1 => {
+                let item = set.pop_front(list_indices[0]);
+                assert_eq!(item, Some(expected[0].remove(0)));
+            }
+            2 => {
+                let item = set.pop_back(list_indices[2]);
+                assert_eq!(item, expected[2].pop());
+            }
+            _ => {
+                set.move_to_back(list_indices[1], 0, list_indices[0]);
+                let item = expected[1].remove(0);
+                expected[0].push(item);
+            }
+        }
+        for (list_index, items) in list_indices.iter().zip(expected.iter()) {
+            assert_eq!(&set.iter(*list_index).copied().collect::<Vec<usize>>(), items);
+        }
+    }
+    while !set.defragment(100) {}
+    let storage_order: Vec<usize> = set.nodes().map(|(_, item)| *item).collect();
+    let expected_storage_order: Vec<usize> = expected.concat();
+
+    assert_eq!(storage_order, expected_storage_order);
+}
+
+/// Every list built with a single extend, or any list after a compaction, 
+/// should be available as one contiguous slice.
+#[test]
+fn test_try_as_slices_contiguous_lists() {
+    let mut set: SoaLinkedListSet<usize> = SoaLinkedListSet::default();
+    let list_indices = [set.new_list(), set.new_list(), set.new_list()];
+    set.extend(list_indices[0], vec![1, 2, 3]);
+    set.extend(list_indices[1], vec![4, 5]);
+
+    assert_eq!(set.try_as_slices(list_indices[0]), Some((&[1, 2, 3][..], &[][..])));
+    assert_eq!(set.try_as_slices(list_indices[1]), Some((&[4, 5][..], &[][..])));
+    assert_eq!(set.try_as_slices(list_indices[2]), Some((&[][..], &[][..])));
+
+    for item in 0..10 {
+        set.push_back(list_indices[item % 3], 10 + item);
+    }
+    set.compact();
+
+    for list_index in list_indices.iter().copied() {
+        let expected: Vec<usize> = set.iter(list_index).copied().collect();
+        let (front, back) = set.try_as_slices(list_index).unwrap();
+
+        assert_eq!(front, &expected[..]);
+        assert!(back.is_empty());
+    }
+}
+
+/// A list split into two runs in the underlying storage should come back as
+/// two slices in list order, and a list split into more runs than that 
+/// should have no slice view.
+#[test]
+fn test_try_as_slices_fragmented_lists() {
+    let mut set: SoaLinkedListSet<i32> = SoaLinkedListSet::default();
+    let list_indices = [set.new_list(), set.new_list()];
+    set.extend(list_indices[0], vec![1, 2, 3]);
+    set.push_back(list_indices[1], 10);
+    set.push_front(list_indices[0], 0);
+
+    assert_eq!(set.try_as_slices(list_indices[0]), Some((&[0][..], &[1, 2, 3][..])));
+
+    set.push_back(list_indices[0], 4);
+
+    assert_eq!(set.try_as_slices(list_indices[0]), None);
+
+    set.pop_front(list_indices[1]);
+
+    assert_eq!(set.try_as_slices(list_indices[0]), Some((&[0][..], &[1, 2, 3, 4][..])));
+}
+
+/// A set with a narrow index type should hold one node fewer than the number 
+/// of values of the index type, and report a capacity overflow instead of 
+/// adding a node past that.
+#[test]
+fn test_index_type_capacity_overflow() {
+    let mut set: LinkedListSet<usize, u8> = LinkedListSet::default();
+    let list_indices = [set.new_list(), set.new_list()];
+    for item in 0..(u8::MAX as usize) {
+        set.push_back(list_indices[item % 2], item);
+    }
+
+    assert_eq!(set.node_count(), u8::MAX as usize);
+    assert_eq!(set.try_push_back(list_indices[0], 0), Err(ListSetError::CapacityOverflow));
+    assert_eq!(set.try_push_front(list_indices[1], 0), Err(ListSetError::CapacityOverflow));
+    assert_eq!(set.try_reserve(1), Err(ListSetError::CapacityOverflow));
+    assert_eq!(set.node_count(), u8::MAX as usize);
+
+    set.pop_front(list_indices[0]);
+
+    assert!(set.try_push_back(list_indices[1], 300).is_ok());
+    assert_eq!(set.back(list_indices[1]), Some(&300));
+}
+
+/// Pushing past the capacity of the index type should panic.
+#[test]
+#[should_panic]
+fn test_index_type_capacity_overflow_panics() {
+    let mut set: LinkedListSet<usize, u8> = LinkedListSet::default();
+    let list_index = set.new_list();
+    for item in 0..=(u8::MAX as usize) {
+        set.push_back(list_index, item);
+    }
+}
+
+/// A set with a narrow index type should hold one linked list fewer than the 
+/// number of values of the index type, and report a capacity overflow 
+/// instead of adding a list past that.
+#[test]
+fn test_index_type_list_capacity_overflow() {
+    let mut set: LinkedListSet<usize, u8> = LinkedListSet::default();
+    let list_indices: Vec<ListIndex<u8>> = (0..u8::MAX).map(|_| set.new_list()).collect();
+
+    assert_eq!(set.list_count(), u8::MAX as usize);
+    assert_eq!(set.try_new_list(), Err(ListSetError::CapacityOverflow));
+
+    set.remove_list(list_indices[7]);
+    let list_index = set.try_new_list().unwrap();
+    set.push_back(list_index, 1);
+
+    assert_eq!(set.front(list_index), Some(&1));
+    assert!(!set.contains_list(list_indices[7]));
+}
+
+/// Creating a list past the capacity of the index type should panic.
+#[test]
+#[should_panic]
+fn test_index_type_list_capacity_overflow_panics() {
+    let mut set: LinkedListSet<usize, u8> = LinkedListSet::default();
+    for _ in 0..=u8::MAX {
+        set.new_list();
+    }
+}
+
+/// A set with a narrow index type should behave the same as a set with the 
+/// default index type.
+#[test]
+fn test_narrow_index_type_matches_default_index_type() {
+    let mut set: LinkedListSet<usize> = LinkedListSet::new();
+    let mut narrow_set: SoaLinkedListSet<usize, u16> = SoaLinkedListSet::default();
+    let list_indices = [set.new_list(), set.new_list()];
+    let narrow_list_indices = [narrow_set.new_list(), narrow_set.new_list()];
+    for item in 0..100 {
+        set.push_back(list_indices[item % 2], (item * 37) % 100);
+        narrow_set.push_back(narrow_list_indices[item % 2], (item * 37) % 100);
+    }
+    for (list_index, narrow_list_index) in list_indices.iter().zip(narrow_list_indices.iter()) {
+        set.retain(*list_index, |item| item % 3 != 0);
+        narrow_set.retain(*narrow_list_index, |item| item % 3 != 0);
+        set.sort(*list_index);
+        narrow_set.sort(*narrow_list_index);
+    }
+    set.append(list_indices[0], list_indices[1]);
+    narrow_set.append(narrow_list_indices[0], narrow_list_indices[1]);
+    narrow_set.compact();
+
+    let expected: Vec<usize> = set.iter(list_indices[0]).copied().collect();
+    let result: Vec<usize> = narrow_set.iter(narrow_list_indices[0]).copied().collect();
+
+    assert_eq!(result, expected);
+    assert_eq!(narrow_set.try_as_slices(narrow_list_indices[0]), Some((&expected[..], &[][..])));
+}
+
+
+/// A set with the structure-of-arrays layout should behave the same as a set 
+/// with the default layout.
+#[test]
+fn test_soa_layout_matches_default_layout() {
+    let mut set: LinkedListSet<usize> = LinkedListSet::new();
+    let mut soa_set: SoaLinkedListSet<usize> = SoaLinkedListSet::default();
+    let list_indices = [set.new_list(), set.new_list(), set.new_list()];
+    let soa_list_indices = [soa_set.new_list(), soa_set.new_list(), soa_set.new_list()];
+    for item in 0..200 {
+        set.push_back(list_indices[item % 3], (item * 37) % 200);
+        soa_set.push_back(soa_list_indices[item % 3], (item * 37) % 200);
+        if item % 7 == 0 {
+            set.pop_front(list_indices[(item + 1) % 3]);
+            soa_set.pop_front(soa_list_indices[(item + 1) % 3]);
+        }
+    }
+    for (list_index, soa_list_index) in list_indices.iter().zip(soa_list_indices.iter()) {
+        set.retain(*list_index, |item| item % 5 != 0);
+        soa_set.retain(*soa_list_index, |item| item % 5 != 0);
+        set.sort(*list_index);
+        soa_set.sort(*soa_list_index);
+        set.iter_mut(*list_index).for_each(|item| *item += 1);
+        soa_set.iter_mut(*soa_list_index).for_each(|item| *item += 1);
+    }
+    set.append(list_indices[0], list_indices[2]);
+    soa_set.append(soa_list_indices[0], soa_list_indices[2]);
+    while !set.defragment(16) {}
+    while !soa_set.defragment(16) {}
+
+    for (list_index, soa_list_index) in list_indices.iter().zip(soa_list_indices.iter()) {
+        let expected: Vec<usize> = set.iter(*list_index).copied().collect();
+        let result: Vec<usize> = soa_set.iter(*soa_list_index).copied().collect();
+
+        assert_eq!(result, expected);
+    }
+
+    let expected: Vec<usize> = set.nodes().map(|(_, item)| *item).collect();
+    let result: Vec<usize> = soa_set.nodes().map(|(_, item)| *item).collect();
+
+    assert_eq!(result, expected);
+}
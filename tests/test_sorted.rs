@@ -0,0 +1,83 @@
+extern crate list_set;
+
+
+use list_set::*;
+
+
+/// The elements of every list in a sorted linked list set should come out in
+/// ascending order, however the items are inserted.
+#[test]
+fn test_sorted_list_set_keeps_lists_sorted() {
+    let mut set = SortedListSet::new();
+    let list_indices = [set.new_list(), set.new_list()];
+    for item in 0..50 {
+        set.insert(list_indices[0], (item * 17) % 50);
+        set.insert(list_indices[1], 50 - item);
+    }
+    let result0: Vec<usize> = set.iter(list_indices[0]).copied().collect();
+    let result1: Vec<usize> = set.iter(list_indices[1]).copied().collect();
+
+    assert_eq!(result0, (0..50).collect::<Vec<usize>>());
+    assert_eq!(result1, (1..=50).collect::<Vec<usize>>());
+    assert_eq!(set.front(list_indices[1]), Some(&1));
+    assert_eq!(set.back(list_indices[1]), Some(&50));
+}
+
+/// Searching a sorted list should find the items in the list, and only the
+/// items in the list.
+#[test]
+fn test_sorted_list_set_contains_and_remove_item() {
+    let mut set = SortedListSet::new();
+    let list_index = set.new_list();
+    set.extend(list_index, vec![8, 2, 6, 4, 2]);
+
+    assert!(set.contains(list_index, &2));
+    assert!(set.contains(list_index, &8));
+    assert!(!set.contains(list_index, &0));
+    assert!(!set.contains(list_index, &5));
+    assert!(!set.contains(list_index, &9));
+    assert_eq!(set.remove_item(list_index, &2), Some(2));
+    assert!(set.contains(list_index, &2));
+    assert_eq!(set.remove_item(list_index, &2), Some(2));
+    assert!(!set.contains(list_index, &2));
+    assert_eq!(set.remove_item(list_index, &7), None);
+    assert_eq!(set.iter(list_index).copied().collect::<Vec<usize>>(), vec![4, 6, 8]);
+}
+
+/// Converting a linked list set into a sorted linked list set should sort 
+/// every list, and keep the list indices valid.
+#[test]
+fn test_sorted_list_set_from_linked_list_set() {
+    let mut set = LinkedListSet::new();
+    let list_indices = [set.new_list(), set.new_list()];
+    set.extend(list_indices[0], vec![3, 1, 2]);
+    set.extend(list_indices[1], vec![9, 7, 8, 7]);
+    let mut sorted_set = SortedListSet::from(set);
+    sorted_set.dedup(list_indices[1]);
+
+    assert_eq!(sorted_set.iter(list_indices[0]).copied().collect::<Vec<usize>>(), vec![1, 2, 3]);
+    assert_eq!(sorted_set.iter(list_indices[1]).copied().collect::<Vec<usize>>(), vec![7, 8, 9]);
+
+    sorted_set.merge(list_indices[0], list_indices[1]);
+    let set = sorted_set.into_set();
+
+    assert!(set.is_sorted(list_indices[0]));
+    assert_eq!(set.len(list_indices[0]), 6);
+}
+
+/// A node handle created by a different set should not point to an element 
+/// of a sorted linked list set.
+#[test]
+fn test_sorted_list_set_rejects_foreign_node_handle() {
+    let mut set = SortedListSet::new();
+    let mut other_set = SortedListSet::new();
+    let list_index = set.new_list();
+    let other_list_index = other_set.new_list();
+    let handle = set.insert(list_index, 1);
+    other_set.insert(other_list_index, 2);
+
+    assert_eq!(other_set.get(handle), None);
+    assert_eq!(other_set.remove_node(handle), None);
+    assert_eq!(other_set.iter(other_list_index).copied().collect::<Vec<usize>>(), vec![2]);
+    assert_eq!(set.get(handle), Some(&1));
+}